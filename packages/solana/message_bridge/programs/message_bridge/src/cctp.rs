@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+
+use crate::error::MessageBridgeError;
+
+/// Anchor's instruction discriminator convention: first 8 bytes of
+/// `sha256("global:<name>")`. The Circle CCTP programs are themselves Anchor
+/// programs, so their instructions are dispatched the same way.
+fn sighash(name: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", name);
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(&hash(preimage.as_bytes()).to_bytes()[..8]);
+    discriminator
+}
+
+/// CPI into the Circle CCTP Token Messenger Minter `deposit_for_burn` instruction,
+/// burning `amount` of the token held in `depositor_token_account` and emitting a
+/// CCTP message addressed to `destination_domain`/`mint_recipient`.
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_for_burn<'info>(
+    token_messenger_minter_program: &AccountInfo<'info>,
+    message_transmitter_program: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    depositor_token_account: &AccountInfo<'info>,
+    message_transmitter: &AccountInfo<'info>,
+    token_messenger: &AccountInfo<'info>,
+    remote_token_messenger: &AccountInfo<'info>,
+    token_minter: &AccountInfo<'info>,
+    local_token: &AccountInfo<'info>,
+    usdc_mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+    destination_domain: u32,
+    mint_recipient: [u8; 32],
+) -> Result<()> {
+    let mut data = sighash("deposit_for_burn").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&destination_domain.to_le_bytes());
+    data.extend_from_slice(&mint_recipient);
+    data.extend_from_slice(usdc_mint.key.as_ref());
+
+    let accounts = vec![
+        AccountMeta::new(*owner.key, true),
+        AccountMeta::new(*depositor_token_account.key, false),
+        AccountMeta::new(*message_transmitter.key, false),
+        AccountMeta::new_readonly(*token_messenger.key, false),
+        AccountMeta::new_readonly(*remote_token_messenger.key, false),
+        AccountMeta::new(*token_minter.key, false),
+        AccountMeta::new(*local_token.key, false),
+        AccountMeta::new(*usdc_mint.key, false),
+        AccountMeta::new_readonly(*message_transmitter_program.key, false),
+        AccountMeta::new_readonly(*token_program.key, false),
+        AccountMeta::new_readonly(*system_program.key, false),
+    ];
+
+    let ix = Instruction {
+        program_id: *token_messenger_minter_program.key,
+        accounts,
+        data,
+    };
+
+    invoke(
+        &ix,
+        &[
+            owner.clone(),
+            depositor_token_account.clone(),
+            message_transmitter.clone(),
+            token_messenger.clone(),
+            remote_token_messenger.clone(),
+            token_minter.clone(),
+            local_token.clone(),
+            usdc_mint.clone(),
+            message_transmitter_program.clone(),
+            token_program.clone(),
+            system_program.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
+
+/// CPI into the Circle CCTP Token Messenger Minter `deposit_for_burn_with_caller`
+/// instruction - identical to `deposit_for_burn`, but restricts who may call
+/// the paired `receive_message` on the destination domain to `destination_caller`,
+/// so only that address (rather than an arbitrary relayer) can complete the
+/// mint. Returns the CCTP nonce assigned to the burn, read back
+/// from the CPI's return data, so the caller can pair it with a Wormhole
+/// `CctpTransferEnvelope`.
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_for_burn_with_caller<'info>(
+    token_messenger_minter_program: &AccountInfo<'info>,
+    message_transmitter_program: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    depositor_token_account: &AccountInfo<'info>,
+    message_transmitter: &AccountInfo<'info>,
+    token_messenger: &AccountInfo<'info>,
+    remote_token_messenger: &AccountInfo<'info>,
+    token_minter: &AccountInfo<'info>,
+    local_token: &AccountInfo<'info>,
+    usdc_mint: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+    destination_domain: u32,
+    mint_recipient: [u8; 32],
+    destination_caller: [u8; 32],
+) -> Result<u64> {
+    let mut data = sighash("deposit_for_burn_with_caller").to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.extend_from_slice(&destination_domain.to_le_bytes());
+    data.extend_from_slice(&mint_recipient);
+    data.extend_from_slice(usdc_mint.key.as_ref());
+    data.extend_from_slice(&destination_caller);
+
+    let accounts = vec![
+        AccountMeta::new(*owner.key, true),
+        AccountMeta::new(*depositor_token_account.key, false),
+        AccountMeta::new(*message_transmitter.key, false),
+        AccountMeta::new_readonly(*token_messenger.key, false),
+        AccountMeta::new_readonly(*remote_token_messenger.key, false),
+        AccountMeta::new(*token_minter.key, false),
+        AccountMeta::new(*local_token.key, false),
+        AccountMeta::new(*usdc_mint.key, false),
+        AccountMeta::new_readonly(*message_transmitter_program.key, false),
+        AccountMeta::new_readonly(*token_program.key, false),
+        AccountMeta::new_readonly(*system_program.key, false),
+    ];
+
+    let ix = Instruction {
+        program_id: *token_messenger_minter_program.key,
+        accounts,
+        data,
+    };
+
+    invoke(
+        &ix,
+        &[
+            owner.clone(),
+            depositor_token_account.clone(),
+            message_transmitter.clone(),
+            token_messenger.clone(),
+            remote_token_messenger.clone(),
+            token_minter.clone(),
+            local_token.clone(),
+            usdc_mint.clone(),
+            message_transmitter_program.clone(),
+            token_program.clone(),
+            system_program.clone(),
+        ],
+    )?;
+
+    let (_, return_data) = anchor_lang::solana_program::program::get_return_data()
+        .ok_or(error!(MessageBridgeError::CctpNonceMismatch))?;
+    let nonce_bytes: [u8; 8] = return_data
+        .try_into()
+        .map_err(|_| error!(MessageBridgeError::CctpNonceMismatch))?;
+
+    Ok(u64::from_le_bytes(nonce_bytes))
+}
+
+/// CPI into the Circle CCTP Message Transmitter `receive_message` instruction,
+/// verifying the guardian attestation and letting the Token Messenger Minter
+/// mint `message.amount` USDC to the recipient in the same CPI chain.
+#[allow(clippy::too_many_arguments)]
+pub fn receive_message<'info>(
+    message_transmitter_program: &AccountInfo<'info>,
+    token_messenger_minter_program: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    message_transmitter: &AccountInfo<'info>,
+    used_nonces: &AccountInfo<'info>,
+    token_messenger: &AccountInfo<'info>,
+    remote_token_messenger: &AccountInfo<'info>,
+    token_minter: &AccountInfo<'info>,
+    local_token: &AccountInfo<'info>,
+    usdc_mint: &AccountInfo<'info>,
+    recipient_token_account: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    message: Vec<u8>,
+    attestation: Vec<u8>,
+) -> Result<()> {
+    let mut data = sighash("receive_message").to_vec();
+    data.extend_from_slice(&(message.len() as u32).to_le_bytes());
+    data.extend_from_slice(&message);
+    data.extend_from_slice(&(attestation.len() as u32).to_le_bytes());
+    data.extend_from_slice(&attestation);
+
+    let accounts = vec![
+        AccountMeta::new(*payer.key, true),
+        AccountMeta::new(*message_transmitter.key, false),
+        AccountMeta::new(*used_nonces.key, false),
+        AccountMeta::new_readonly(*token_messenger_minter_program.key, false),
+        AccountMeta::new_readonly(*token_messenger.key, false),
+        AccountMeta::new_readonly(*remote_token_messenger.key, false),
+        AccountMeta::new(*token_minter.key, false),
+        AccountMeta::new(*local_token.key, false),
+        AccountMeta::new(*usdc_mint.key, false),
+        AccountMeta::new(*recipient_token_account.key, false),
+        AccountMeta::new_readonly(*token_program.key, false),
+        AccountMeta::new_readonly(*system_program.key, false),
+    ];
+
+    let ix = Instruction {
+        program_id: *message_transmitter_program.key,
+        accounts,
+        data,
+    };
+
+    invoke(
+        &ix,
+        &[
+            payer.clone(),
+            message_transmitter.clone(),
+            used_nonces.clone(),
+            token_messenger_minter_program.clone(),
+            token_messenger.clone(),
+            remote_token_messenger.clone(),
+            token_minter.clone(),
+            local_token.clone(),
+            usdc_mint.clone(),
+            recipient_token_account.clone(),
+            token_program.clone(),
+            system_program.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
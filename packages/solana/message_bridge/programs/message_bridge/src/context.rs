@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use wormhole_anchor_sdk::wormhole::{self, program::Wormhole};
 
 use crate::error::MessageBridgeError;
@@ -111,6 +112,100 @@ pub struct RegisterEmitter<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Context for mapping a Wormhole chain ID to a CCTP destination domain
+#[derive(Accounts)]
+pub struct SetCctpDomain<'info> {
+    /// Program owner
+    pub owner: Signer<'info>,
+
+    /// Config account (must match owner)
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+        has_one = owner @ MessageBridgeError::OwnerOnly
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Context for pausing or unpausing `send_value`/`receive_value`
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    /// Program owner
+    pub owner: Signer<'info>,
+
+    /// Config account (must match owner)
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+        has_one = owner @ MessageBridgeError::OwnerOnly
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Context for revoking a registered foreign emitter
+#[derive(Accounts)]
+#[instruction(chain_id: u16)]
+pub struct DeregisterEmitter<'info> {
+    /// Program owner
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Config account (must match owner)
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump,
+        has_one = owner @ MessageBridgeError::OwnerOnly
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Foreign emitter account to close (PDA by chain_id)
+    #[account(
+        mut,
+        close = owner,
+        seeds = [ForeignEmitter::SEED_PREFIX, &chain_id.to_le_bytes()],
+        bump
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+}
+
+/// Context for staging an ownership rotation
+#[derive(Accounts)]
+pub struct TransferOwnership<'info> {
+    /// Current program owner
+    pub owner: Signer<'info>,
+
+    /// Config account (must match owner)
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+        has_one = owner @ MessageBridgeError::OwnerOnly
+    )]
+    pub config: Account<'info, Config>,
+}
+
+/// Context for completing a staged ownership rotation
+///
+/// Unlike every other owner-gated context, the signer here is the *pending*
+/// owner, not the current one - `accept_ownership` is how that pending key
+/// proves it controls the address being promoted.
+#[derive(Accounts)]
+pub struct AcceptOwnership<'info> {
+    /// Pending owner, staged by a prior `transfer_ownership`
+    pub pending_owner: Signer<'info>,
+
+    /// Config account (must have a matching pending_owner)
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+        has_one = pending_owner @ MessageBridgeError::OwnerOnly
+    )]
+    pub config: Account<'info, Config>,
+}
+
 /// Context for sending a value to another chain
 #[derive(Accounts)]
 pub struct SendValue<'info> {
@@ -203,10 +298,15 @@ pub struct ReceiveValue<'info> {
     /// Wormhole program
     pub wormhole_program: Program<'info, Wormhole>,
 
-    /// Posted VAA account (verified by Wormhole)
-    /// CHECK: Verified by Wormhole program, we parse the data manually
+    /// Posted VAA account
+    /// CHECK: `PostedVaaV1::load` recomputes the VAA's `keccak256` hash from
+    /// its fields and `require_eq!`s it against `vaa_hash`, so a mismatched
+    /// account is rejected there; `seeds = [b"PostedVAA", &vaa_hash]` under
+    /// the Wormhole program is a second, independent pin to the same hash.
     #[account(
-        owner = wormhole_program.key()
+        seeds = [b"PostedVAA", &vaa_hash],
+        bump,
+        seeds::program = wormhole_program.key()
     )]
     pub posted_vaa: AccountInfo<'info>,
 
@@ -237,3 +337,1038 @@ pub struct ReceiveValue<'info> {
     pub system_program: Program<'info, System>,
 }
 
+/// Context for sending a batch of value transfers to another chain
+///
+/// Identical account set to `SendValue` - batches are posted through the same
+/// emitter/message/sequence accounts, just with a `BatchMessage` payload.
+#[derive(Accounts)]
+pub struct SendBatch<'info> {
+    /// Payer for Wormhole fee
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Config account
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Wormhole emitter (PDA that signs messages)
+    #[account(
+        seeds = [WormholeEmitter::SEED_PREFIX],
+        bump = wormhole_emitter.bump,
+    )]
+    pub wormhole_emitter: Account<'info, WormholeEmitter>,
+
+    /// Wormhole program
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    /// Wormhole bridge data
+    /// CHECK: Verified by address constraint
+    #[account(
+        mut,
+        address = config.wormhole_bridge @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_bridge: AccountInfo<'info>,
+
+    /// Wormhole fee collector
+    /// CHECK: Verified by address constraint
+    #[account(
+        mut,
+        address = config.wormhole_fee_collector @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_fee_collector: AccountInfo<'info>,
+
+    /// Wormhole sequence tracker
+    #[account(
+        mut,
+        address = config.wormhole_sequence @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    /// CHECK: Wormhole sequence account
+    pub wormhole_sequence: AccountInfo<'info>,
+
+    /// Wormhole message account (PDA)
+    #[account(
+        mut,
+        seeds = [
+            b"message",
+            &config.nonce.to_le_bytes()
+        ],
+        bump
+    )]
+    /// CHECK: Wormhole message account, created by this program
+    pub wormhole_message: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for receiving a batch of value transfers from another chain
+///
+/// Unlike `ReceiveValue`, the number of `ReceivedMessage` replay-protection
+/// PDAs is not known until the VAA payload is decoded (one per batch entry),
+/// so they are passed in `ctx.remaining_accounts` (in entry order) and
+/// created manually in the instruction handler instead of being declared here.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32], emitter_chain: u16, sequence: u64)]
+pub struct ReceiveBatch<'info> {
+    /// Payer for account creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Config account
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Current value storage (to update)
+    #[account(
+        mut,
+        seeds = [CurrentValue::SEED_PREFIX],
+        bump,
+    )]
+    pub current_value: Account<'info, CurrentValue>,
+
+    /// Wormhole program
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    /// Posted VAA account
+    /// CHECK: `PostedVaaV1::load` recomputes the VAA's `keccak256` hash from
+    /// its fields and `require_eq!`s it against `vaa_hash`, so a mismatched
+    /// account is rejected there; `seeds = [b"PostedVAA", &vaa_hash]` under
+    /// the Wormhole program is a second, independent pin to the same hash.
+    #[account(
+        seeds = [b"PostedVAA", &vaa_hash],
+        bump,
+        seeds::program = wormhole_program.key()
+    )]
+    pub posted_vaa: AccountInfo<'info>,
+
+    /// Foreign emitter (must match VAA emitter - validation done in instruction)
+    #[account(
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &emitter_chain.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+/// Context for sending a value together with a CCTP USDC deposit-for-burn
+///
+/// Posts the same `ValueMessage` VAA as `SendValue`, binding it to a CCTP
+/// burn of `value` USDC so the destination chain can redeem both atomically.
+#[derive(Accounts)]
+pub struct SendValueWithCctp<'info> {
+    /// Payer for Wormhole fee and CPI rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Config account
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Wormhole emitter (PDA that signs messages)
+    #[account(
+        seeds = [WormholeEmitter::SEED_PREFIX],
+        bump = wormhole_emitter.bump,
+    )]
+    pub wormhole_emitter: Account<'info, WormholeEmitter>,
+
+    /// Wormhole program
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    /// Wormhole bridge data
+    /// CHECK: Verified by address constraint
+    #[account(
+        mut,
+        address = config.wormhole_bridge @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_bridge: AccountInfo<'info>,
+
+    /// Wormhole fee collector
+    /// CHECK: Verified by address constraint
+    #[account(
+        mut,
+        address = config.wormhole_fee_collector @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_fee_collector: AccountInfo<'info>,
+
+    /// Wormhole sequence tracker
+    /// CHECK: Wormhole sequence account
+    #[account(
+        mut,
+        address = config.wormhole_sequence @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_sequence: AccountInfo<'info>,
+
+    /// Wormhole message account (PDA)
+    /// CHECK: Wormhole message account, created by this program
+    #[account(
+        mut,
+        seeds = [b"message", &config.nonce.to_le_bytes()],
+        bump
+    )]
+    pub wormhole_message: AccountInfo<'info>,
+
+    /// USDC mint, supply decreases by `value` on burn
+    #[account(
+        mut,
+        address = config.usdc_mint @ MessageBridgeError::CctpMintMismatch
+    )]
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Depositor's USDC token account, debited by `value`
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = payer,
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+
+    /// Circle CCTP Token Messenger Minter program
+    /// CHECK: Verified by address constraint
+    #[account(address = config.cctp_token_messenger_minter)]
+    pub token_messenger_minter_program: AccountInfo<'info>,
+
+    /// CCTP token messenger state (owned by `token_messenger_minter_program`)
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    #[account(mut)]
+    pub token_messenger: AccountInfo<'info>,
+
+    /// CCTP remote token messenger for the destination domain
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    pub remote_token_messenger: AccountInfo<'info>,
+
+    /// CCTP token minter state
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    #[account(mut)]
+    pub token_minter: AccountInfo<'info>,
+
+    /// CCTP per-mint local token config
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    #[account(mut)]
+    pub local_token: AccountInfo<'info>,
+
+    /// CCTP Message Transmitter program
+    /// CHECK: Verified by address constraint
+    #[account(address = config.cctp_message_transmitter)]
+    pub message_transmitter_program: AccountInfo<'info>,
+
+    /// CCTP message transmitter state (tracks the outbound CCTP nonce)
+    /// CHECK: Verified by the Message Transmitter program during its own CPI
+    #[account(mut)]
+    pub message_transmitter: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for completing a value transfer that was bound to a CCTP USDC burn
+///
+/// Verifies the Wormhole VAA (replay-protected by `ReceivedMessage`, like
+/// `ReceiveValue`) and the CCTP mint receipt before crediting `CurrentValue`.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32], emitter_chain: u16, sequence: u64)]
+pub struct ReceiveValueWithCctp<'info> {
+    /// Payer for account creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Config account
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Current value storage (to update)
+    #[account(
+        mut,
+        seeds = [CurrentValue::SEED_PREFIX],
+        bump,
+    )]
+    pub current_value: Account<'info, CurrentValue>,
+
+    /// Wormhole program
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    /// Posted VAA account
+    /// CHECK: `PostedVaaV1::load` recomputes the VAA's `keccak256` hash from
+    /// its fields and `require_eq!`s it against `vaa_hash`, so a mismatched
+    /// account is rejected there; `seeds = [b"PostedVAA", &vaa_hash]` under
+    /// the Wormhole program is a second, independent pin to the same hash.
+    #[account(
+        seeds = [b"PostedVAA", &vaa_hash],
+        bump,
+        seeds::program = wormhole_program.key()
+    )]
+    pub posted_vaa: AccountInfo<'info>,
+
+    /// Foreign emitter (must match VAA emitter - validation done in instruction)
+    #[account(
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &emitter_chain.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    /// Received message account (for replay protection)
+    #[account(
+        init,
+        payer = payer,
+        space = ReceivedMessage::SPACE,
+        seeds = [
+            ReceivedMessage::SEED_PREFIX,
+            &emitter_chain.to_le_bytes(),
+            &sequence.to_le_bytes()
+        ],
+        bump
+    )]
+    pub received_message: Account<'info, ReceivedMessage>,
+
+    /// USDC mint the CCTP mint receipt is expected to credit
+    #[account(
+        mut,
+        address = config.usdc_mint @ MessageBridgeError::CctpMintMismatch
+    )]
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Recipient USDC token account, credited by the CCTP mint CPI
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    /// Circle CCTP Message Transmitter program
+    /// CHECK: Verified by address constraint
+    #[account(address = config.cctp_message_transmitter)]
+    pub message_transmitter_program: AccountInfo<'info>,
+
+    /// CCTP message transmitter state (tracks used nonces for replay protection)
+    /// CHECK: Verified by the Message Transmitter program during its own CPI
+    #[account(mut)]
+    pub message_transmitter: AccountInfo<'info>,
+
+    /// CCTP used-nonces account for the source domain
+    /// CHECK: Verified by the Message Transmitter program during its own CPI
+    #[account(mut)]
+    pub used_nonces: AccountInfo<'info>,
+
+    /// Circle CCTP Token Messenger Minter program
+    /// CHECK: Verified by address constraint
+    #[account(address = config.cctp_token_messenger_minter)]
+    pub token_messenger_minter_program: AccountInfo<'info>,
+
+    /// CCTP token messenger state
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    pub token_messenger: AccountInfo<'info>,
+
+    /// CCTP remote token messenger for the source domain
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    pub remote_token_messenger: AccountInfo<'info>,
+
+    /// CCTP token minter state
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    #[account(mut)]
+    pub token_minter: AccountInfo<'info>,
+
+    /// CCTP per-mint local token config
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    #[account(mut)]
+    pub local_token: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Context for receiving a text message from another chain
+///
+/// `message_inbox` is created lazily on the first message from a given
+/// `emitter_chain` and reused (as a ring buffer) for subsequent ones.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32], emitter_chain: u16, sequence: u64)]
+pub struct ReceiveText<'info> {
+    /// Payer for account creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Config account
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Wormhole program
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    /// Posted VAA account
+    /// CHECK: `PostedVaaV1::load` recomputes the VAA's `keccak256` hash from
+    /// its fields and `require_eq!`s it against `vaa_hash`, so a mismatched
+    /// account is rejected there; `seeds = [b"PostedVAA", &vaa_hash]` under
+    /// the Wormhole program is a second, independent pin to the same hash.
+    #[account(
+        seeds = [b"PostedVAA", &vaa_hash],
+        bump,
+        seeds::program = wormhole_program.key()
+    )]
+    pub posted_vaa: AccountInfo<'info>,
+
+    /// Foreign emitter (must match VAA emitter - validation done in instruction)
+    #[account(
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &emitter_chain.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    /// Received message account (for replay protection)
+    #[account(
+        init,
+        payer = payer,
+        space = ReceivedMessage::SPACE,
+        seeds = [
+            ReceivedMessage::SEED_PREFIX,
+            &emitter_chain.to_le_bytes(),
+            &sequence.to_le_bytes()
+        ],
+        bump
+    )]
+    pub received_message: Account<'info, ReceivedMessage>,
+
+    /// Per-chain ring buffer of recent text messages
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MessageInbox::SPACE,
+        seeds = [MessageInbox::SEED_PREFIX, &emitter_chain.to_le_bytes()],
+        bump
+    )]
+    pub message_inbox: Account<'info, MessageInbox>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Context for sending an arbitrary-payload message to another chain
+///
+/// Identical account set to `SendValue`/`SendBatch` - the general-purpose
+/// message bus posts through the same emitter/message/sequence accounts,
+/// just with a `PayloadKind` payload.
+#[derive(Accounts)]
+pub struct SendMessage<'info> {
+    /// Payer for Wormhole fee
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Config account
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Wormhole emitter (PDA that signs messages)
+    #[account(
+        seeds = [WormholeEmitter::SEED_PREFIX],
+        bump = wormhole_emitter.bump,
+    )]
+    pub wormhole_emitter: Account<'info, WormholeEmitter>,
+
+    /// Wormhole program
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    /// Wormhole bridge data
+    /// CHECK: Verified by address constraint
+    #[account(
+        mut,
+        address = config.wormhole_bridge @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_bridge: AccountInfo<'info>,
+
+    /// Wormhole fee collector
+    /// CHECK: Verified by address constraint
+    #[account(
+        mut,
+        address = config.wormhole_fee_collector @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_fee_collector: AccountInfo<'info>,
+
+    /// Wormhole sequence tracker
+    #[account(
+        mut,
+        address = config.wormhole_sequence @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    /// CHECK: Wormhole sequence account
+    pub wormhole_sequence: AccountInfo<'info>,
+
+    /// Wormhole message account (PDA)
+    #[account(
+        mut,
+        seeds = [
+            b"message",
+            &config.nonce.to_le_bytes()
+        ],
+        bump
+    )]
+    /// CHECK: Wormhole message account, created by this program
+    pub wormhole_message: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for receiving an arbitrary-payload message from another chain
+///
+/// `message_payload` is created at `MessagePayload::BASE_SPACE` and `realloc`'d
+/// in the handler once the `PayloadKind` has been decoded, since its size
+/// isn't known until then.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32], emitter_chain: u16, sequence: u64)]
+pub struct ReceiveMessage<'info> {
+    /// Payer for account creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Config account
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Wormhole program
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    /// Posted VAA account
+    /// CHECK: `PostedVaaV1::load` recomputes the VAA's `keccak256` hash from
+    /// its fields and `require_eq!`s it against `vaa_hash`, so a mismatched
+    /// account is rejected there; `seeds = [b"PostedVAA", &vaa_hash]` under
+    /// the Wormhole program is a second, independent pin to the same hash.
+    #[account(
+        seeds = [b"PostedVAA", &vaa_hash],
+        bump,
+        seeds::program = wormhole_program.key()
+    )]
+    pub posted_vaa: AccountInfo<'info>,
+
+    /// Foreign emitter (must match VAA emitter - validation done in instruction)
+    #[account(
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &emitter_chain.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    /// Received message account (for replay protection)
+    #[account(
+        init,
+        payer = payer,
+        space = ReceivedMessage::SPACE,
+        seeds = [
+            ReceivedMessage::SEED_PREFIX,
+            &emitter_chain.to_le_bytes(),
+            &sequence.to_le_bytes()
+        ],
+        bump
+    )]
+    pub received_message: Account<'info, ReceivedMessage>,
+
+    /// Decoded payload storage, realloc'd in the handler to fit
+    #[account(
+        init,
+        payer = payer,
+        space = MessagePayload::BASE_SPACE,
+        seeds = [
+            MessagePayload::SEED_PREFIX,
+            &emitter_chain.to_le_bytes(),
+            &sequence.to_le_bytes()
+        ],
+        bump
+    )]
+    pub message_payload: Account<'info, MessagePayload>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for sending an SPL token to another chain via the Wormhole Token Bridge
+///
+/// `custody_or_wrapped_meta`/`custody_signer_or_mint_authority` are whichever
+/// pair of Token Bridge PDAs the transfer needs: the token's custody account
+/// and its signer PDA for a native transfer, or the wrapped asset's metadata
+/// account and mint authority PDA for a wrapped transfer - the instruction's
+/// `is_wrapped` flag picks which.
+#[derive(Accounts)]
+pub struct SendTokens<'info> {
+    /// Payer for Wormhole fee and CPI rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Config account
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Wormhole emitter (PDA that signs messages)
+    #[account(
+        seeds = [WormholeEmitter::SEED_PREFIX],
+        bump = wormhole_emitter.bump,
+    )]
+    pub wormhole_emitter: Account<'info, WormholeEmitter>,
+
+    /// Wormhole program
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    /// Wormhole bridge data
+    /// CHECK: Verified by address constraint
+    #[account(
+        mut,
+        address = config.wormhole_bridge @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_bridge: AccountInfo<'info>,
+
+    /// Wormhole fee collector
+    /// CHECK: Verified by address constraint
+    #[account(
+        mut,
+        address = config.wormhole_fee_collector @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_fee_collector: AccountInfo<'info>,
+
+    /// Wormhole sequence tracker
+    /// CHECK: Verified by address constraint
+    #[account(
+        mut,
+        address = config.wormhole_sequence @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_sequence: AccountInfo<'info>,
+
+    /// Wormhole message account (PDA), created by the Token Bridge's own CPI
+    /// CHECK: Wormhole message account, seeded by this program
+    #[account(
+        mut,
+        seeds = [b"message", &config.nonce.to_le_bytes()],
+        bump
+    )]
+    pub wormhole_message: AccountInfo<'info>,
+
+    /// Wormhole Token Bridge program
+    /// CHECK: Verified by address constraint
+    #[account(address = config.token_bridge_program)]
+    pub token_bridge_program: AccountInfo<'info>,
+
+    /// Token Bridge config (its own PDA, unrelated to ours)
+    /// CHECK: Verified by the Token Bridge program during its own CPI
+    pub token_bridge_config: AccountInfo<'info>,
+
+    /// Mint being transferred (native) or the wrapped mint (wrapped)
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    /// Sender's token account, debited (native) or burned from (wrapped) by `amount`
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = payer,
+    )]
+    pub from_token_account: Account<'info, TokenAccount>,
+
+    /// Token Bridge custody account (native) or wrapped-mint metadata (wrapped)
+    /// CHECK: Verified by the Token Bridge program during its own CPI
+    #[account(mut)]
+    pub custody_or_wrapped_meta: AccountInfo<'info>,
+
+    /// Token Bridge transfer authority signer PDA
+    /// CHECK: Verified by the Token Bridge program during its own CPI
+    pub authority_signer: AccountInfo<'info>,
+
+    /// Token Bridge custody-account signer (native) or mint-authority PDA (wrapped)
+    /// CHECK: Verified by the Token Bridge program during its own CPI
+    #[account(mut)]
+    pub custody_signer_or_mint_authority: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for completing a Wormhole Token Bridge transfer into a recipient ATA
+///
+/// Verifies our own VAA (replay-protected by `ReceivedMessage`, like
+/// `ReceiveValue`) before asking the Token Bridge to complete its companion
+/// transfer VAA, which carries the actual amount and recipient.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32], emitter_chain: u16, sequence: u64)]
+pub struct RedeemTokens<'info> {
+    /// Payer for account creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Config account
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Wormhole program
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    /// Posted VAA account for our own emitter's message
+    /// CHECK: `PostedVaaV1::load` recomputes the VAA's `keccak256` hash from
+    /// its fields and `require_eq!`s it against `vaa_hash`, so a mismatched
+    /// account is rejected there; `seeds = [b"PostedVAA", &vaa_hash]` under
+    /// the Wormhole program is a second, independent pin to the same hash.
+    #[account(
+        seeds = [b"PostedVAA", &vaa_hash],
+        bump,
+        seeds::program = wormhole_program.key()
+    )]
+    pub posted_vaa: AccountInfo<'info>,
+
+    /// Foreign emitter (must match our VAA's emitter - validation done in instruction)
+    #[account(
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &emitter_chain.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    /// Received message account (for replay protection)
+    #[account(
+        init,
+        payer = payer,
+        space = ReceivedMessage::SPACE,
+        seeds = [
+            ReceivedMessage::SEED_PREFIX,
+            &emitter_chain.to_le_bytes(),
+            &sequence.to_le_bytes()
+        ],
+        bump
+    )]
+    pub received_message: Account<'info, ReceivedMessage>,
+
+    /// Wormhole Token Bridge program
+    /// CHECK: Verified by address constraint
+    #[account(address = config.token_bridge_program)]
+    pub token_bridge_program: AccountInfo<'info>,
+
+    /// Token Bridge config
+    /// CHECK: Verified by the Token Bridge program during its own CPI
+    pub token_bridge_config: AccountInfo<'info>,
+
+    /// Posted VAA for the Token Bridge's own companion transfer attestation
+    ///
+    /// `send_tokens` posts exactly one VAA per transfer, through our own
+    /// `wormhole_emitter`/`wormhole_message`, so this is always the *same*
+    /// account as `posted_vaa` above - pinned here so a caller can't satisfy
+    /// the `foreign_emitter`/`received_message` checks against one VAA while
+    /// handing the Token Bridge CPI an unrelated one.
+    /// CHECK: Verified by the Token Bridge program during its own CPI
+    #[account(address = posted_vaa.key())]
+    pub token_bridge_posted_vaa: AccountInfo<'info>,
+
+    /// Token Bridge replay-protection claim PDA for its own VAA
+    /// CHECK: Verified by the Token Bridge program during its own CPI
+    #[account(mut)]
+    pub token_bridge_claim: AccountInfo<'info>,
+
+    /// Token Bridge registered foreign endpoint for the source chain
+    /// CHECK: Verified by the Token Bridge program during its own CPI
+    pub token_bridge_foreign_endpoint: AccountInfo<'info>,
+
+    /// Recipient token account, credited by the Token Bridge CPI
+    #[account(mut)]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    /// Relayer fee recipient token account (may equal `recipient_token_account`)
+    #[account(mut)]
+    pub fee_recipient_token_account: Account<'info, TokenAccount>,
+
+    /// Token Bridge custody account (native) or wrapped mint (wrapped)
+    /// CHECK: Verified by the Token Bridge program during its own CPI
+    #[account(mut)]
+    pub custody_or_wrapped_mint: AccountInfo<'info>,
+
+    /// Token Bridge custody-account signer (native) or mint-authority PDA (wrapped)
+    /// CHECK: Verified by the Token Bridge program during its own CPI
+    #[account(mut)]
+    pub custody_signer_or_mint_authority: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for burning USDC natively via CCTP and posting a paired Wormhole envelope
+///
+/// Identical account set to `SendValueWithCctp`, since both pay the Wormhole
+/// fee and CPI into the Token Messenger Minter the same way - `transfer_usdc`
+/// just calls `deposit_for_burn_with_caller` instead of `deposit_for_burn` and
+/// posts a `CctpTransferEnvelope` instead of a `ValueMessage`.
+#[derive(Accounts)]
+pub struct TransferUsdc<'info> {
+    /// Payer for Wormhole fee and CPI rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Config account
+    #[account(
+        mut,
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Wormhole emitter (PDA that signs messages)
+    #[account(
+        seeds = [WormholeEmitter::SEED_PREFIX],
+        bump = wormhole_emitter.bump,
+    )]
+    pub wormhole_emitter: Account<'info, WormholeEmitter>,
+
+    /// Wormhole program
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    /// Wormhole bridge data
+    /// CHECK: Verified by address constraint
+    #[account(
+        mut,
+        address = config.wormhole_bridge @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_bridge: AccountInfo<'info>,
+
+    /// Wormhole fee collector
+    /// CHECK: Verified by address constraint
+    #[account(
+        mut,
+        address = config.wormhole_fee_collector @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_fee_collector: AccountInfo<'info>,
+
+    /// Wormhole sequence tracker
+    /// CHECK: Wormhole sequence account
+    #[account(
+        mut,
+        address = config.wormhole_sequence @ MessageBridgeError::InvalidWormholeConfig
+    )]
+    pub wormhole_sequence: AccountInfo<'info>,
+
+    /// Wormhole message account (PDA)
+    /// CHECK: Wormhole message account, created by this program
+    #[account(
+        mut,
+        seeds = [b"message", &config.nonce.to_le_bytes()],
+        bump
+    )]
+    pub wormhole_message: AccountInfo<'info>,
+
+    /// USDC mint, supply decreases by `amount` on burn
+    #[account(
+        mut,
+        address = config.usdc_mint @ MessageBridgeError::CctpMintMismatch
+    )]
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Depositor's USDC token account, debited by `amount`
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = payer,
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+
+    /// Circle CCTP Token Messenger Minter program
+    /// CHECK: Verified by address constraint
+    #[account(address = config.cctp_token_messenger_minter)]
+    pub token_messenger_minter_program: AccountInfo<'info>,
+
+    /// CCTP token messenger state (owned by `token_messenger_minter_program`)
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    #[account(mut)]
+    pub token_messenger: AccountInfo<'info>,
+
+    /// CCTP remote token messenger for the destination domain
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    pub remote_token_messenger: AccountInfo<'info>,
+
+    /// CCTP token minter state
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    #[account(mut)]
+    pub token_minter: AccountInfo<'info>,
+
+    /// CCTP per-mint local token config
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    #[account(mut)]
+    pub local_token: AccountInfo<'info>,
+
+    /// CCTP Message Transmitter program
+    /// CHECK: Verified by address constraint
+    #[account(address = config.cctp_message_transmitter)]
+    pub message_transmitter_program: AccountInfo<'info>,
+
+    /// CCTP message transmitter state (tracks the outbound CCTP nonce)
+    /// CHECK: Verified by the Message Transmitter program during its own CPI
+    #[account(mut)]
+    pub message_transmitter: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Context for redeeming a `transfer_usdc` envelope, completing the paired CCTP mint
+///
+/// Verifies the Wormhole VAA (replay-protected via `ReceivedMessage`, like
+/// `ReceiveValueWithCctp`) and decodes its `CctpTransferEnvelope` before
+/// completing the CCTP `receive_message`, whose own nonce must match the
+/// envelope.
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32], emitter_chain: u16, sequence: u64)]
+pub struct RedeemUsdc<'info> {
+    /// Payer for account creation
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Config account
+    #[account(
+        seeds = [Config::SEED_PREFIX],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Current value storage (to update)
+    #[account(
+        mut,
+        seeds = [CurrentValue::SEED_PREFIX],
+        bump,
+    )]
+    pub current_value: Account<'info, CurrentValue>,
+
+    /// Wormhole program
+    pub wormhole_program: Program<'info, Wormhole>,
+
+    /// Posted VAA account
+    /// CHECK: `PostedVaaV1::load` recomputes the VAA's `keccak256` hash from
+    /// its fields and `require_eq!`s it against `vaa_hash`, so a mismatched
+    /// account is rejected there; `seeds = [b"PostedVAA", &vaa_hash]` under
+    /// the Wormhole program is a second, independent pin to the same hash.
+    #[account(
+        seeds = [b"PostedVAA", &vaa_hash],
+        bump,
+        seeds::program = wormhole_program.key()
+    )]
+    pub posted_vaa: AccountInfo<'info>,
+
+    /// Foreign emitter (must match VAA emitter - validation done in instruction)
+    #[account(
+        seeds = [
+            ForeignEmitter::SEED_PREFIX,
+            &emitter_chain.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    /// Received message account (for replay protection)
+    #[account(
+        init,
+        payer = payer,
+        space = ReceivedMessage::SPACE,
+        seeds = [
+            ReceivedMessage::SEED_PREFIX,
+            &emitter_chain.to_le_bytes(),
+            &sequence.to_le_bytes()
+        ],
+        bump
+    )]
+    pub received_message: Account<'info, ReceivedMessage>,
+
+    /// USDC mint the CCTP mint receipt is expected to credit
+    #[account(
+        mut,
+        address = config.usdc_mint @ MessageBridgeError::CctpMintMismatch
+    )]
+    pub usdc_mint: Account<'info, Mint>,
+
+    /// Recipient USDC token account, credited by the CCTP mint CPI
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    /// Circle CCTP Message Transmitter program
+    /// CHECK: Verified by address constraint
+    #[account(address = config.cctp_message_transmitter)]
+    pub message_transmitter_program: AccountInfo<'info>,
+
+    /// CCTP message transmitter state (tracks used nonces for replay protection)
+    /// CHECK: Verified by the Message Transmitter program during its own CPI
+    #[account(mut)]
+    pub message_transmitter: AccountInfo<'info>,
+
+    /// CCTP used-nonces account for the source domain
+    /// CHECK: Verified by the Message Transmitter program during its own CPI
+    #[account(mut)]
+    pub used_nonces: AccountInfo<'info>,
+
+    /// Circle CCTP Token Messenger Minter program
+    /// CHECK: Verified by address constraint
+    #[account(address = config.cctp_token_messenger_minter)]
+    pub token_messenger_minter_program: AccountInfo<'info>,
+
+    /// CCTP token messenger state
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    pub token_messenger: AccountInfo<'info>,
+
+    /// CCTP remote token messenger for the source domain
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    pub remote_token_messenger: AccountInfo<'info>,
+
+    /// CCTP token minter state
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    #[account(mut)]
+    pub token_minter: AccountInfo<'info>,
+
+    /// CCTP per-mint local token config
+    /// CHECK: Verified by the Token Messenger Minter program during its own CPI
+    #[account(mut)]
+    pub local_token: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,14 +1,18 @@
 use anchor_lang::prelude::*;
 use wormhole_anchor_sdk::wormhole;
 
+pub mod cctp;
 pub mod context;
 pub mod error;
 pub mod message;
+pub mod posted_vaa;
 pub mod state;
+pub mod token_bridge;
 
 pub use context::*;
 pub use error::*;
 pub use message::*;
+pub use posted_vaa::*;
 pub use state::*;
 
 declare_id!("7sUZQGRVwV7Cps1zVaASJAJ1N3rgijtX8SbYNt1pej3q");
@@ -27,7 +31,19 @@ pub mod message_bridge {
     ///
     /// This sets up the config account with Wormhole addresses and creates
     /// the emitter PDA that will sign outbound messages.
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+    ///
+    /// # Arguments
+    /// * `cctp_token_messenger_minter` - Circle CCTP Token Messenger Minter program
+    /// * `cctp_message_transmitter` - Circle CCTP Message Transmitter program
+    /// * `usdc_mint` - USDC mint bridged via CCTP
+    /// * `token_bridge_program` - Wormhole Token Bridge program, used by `send_tokens`/`redeem_tokens`
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        cctp_token_messenger_minter: Pubkey,
+        cctp_message_transmitter: Pubkey,
+        usdc_mint: Pubkey,
+        token_bridge_program: Pubkey,
+    ) -> Result<()> {
         // Store emitter bump first (before other borrows)
         ctx.accounts.wormhole_emitter.bump = ctx.bumps.wormhole_emitter;
 
@@ -35,6 +51,8 @@ pub mod message_bridge {
 
         // Store owner
         config.owner = ctx.accounts.owner.key();
+        config.pending_owner = Pubkey::default();
+        config.paused = false;
 
         // Store Wormhole addresses
         config.wormhole_program = ctx.accounts.wormhole_program.key();
@@ -47,6 +65,15 @@ pub mod message_bridge {
         config.chain_id = SOLANA_CHAIN_ID;
         config.nonce = 0;
 
+        // Store CCTP addresses
+        config.cctp_token_messenger_minter = cctp_token_messenger_minter;
+        config.cctp_message_transmitter = cctp_message_transmitter;
+        config.usdc_mint = usdc_mint;
+        config.cctp_domain_count = 0;
+
+        // Store Token Bridge program
+        config.token_bridge_program = token_bridge_program;
+
         // Initialize current value to 0
         ctx.accounts.current_value.value = 0;
 
@@ -57,6 +84,66 @@ pub mod message_bridge {
         Ok(())
     }
 
+    /// Register (or update) the CCTP destination domain for a Wormhole chain ID
+    ///
+    /// Only the owner can configure the mapping used by `send_value_with_cctp`
+    /// to route a deposit-for-burn to the right destination domain.
+    pub fn set_cctp_domain(ctx: Context<SetCctpDomain>, chain_id: u16, domain: u32) -> Result<()> {
+        ctx.accounts.config.set_cctp_domain(chain_id, domain)?;
+
+        msg!("Mapped chain {} to CCTP domain {}", chain_id, domain);
+
+        Ok(())
+    }
+
+    /// Pause or unpause `send_value`/`receive_value`
+    ///
+    /// Only the owner can pause the bridge, e.g. to stop activity while
+    /// investigating a compromised foreign emitter.
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        ctx.accounts.config.paused = paused;
+
+        msg!("Bridge paused: {}", paused);
+
+        Ok(())
+    }
+
+    /// Revoke a registered foreign emitter, e.g. if it is found to be compromised
+    ///
+    /// Only the owner can deregister emitters. Closing the `ForeignEmitter`
+    /// PDA frees it to be re-registered later via `register_emitter`.
+    pub fn deregister_emitter(_ctx: Context<DeregisterEmitter>, chain_id: u16) -> Result<()> {
+        msg!("Deregistered emitter for chain {}", chain_id);
+
+        Ok(())
+    }
+
+    /// Stage an ownership rotation
+    ///
+    /// Only the current owner can stage a transfer; it only takes effect once
+    /// `new_owner` calls `accept_ownership`, so a typo can't lock out the
+    /// config.
+    pub fn transfer_ownership(ctx: Context<TransferOwnership>, new_owner: Pubkey) -> Result<()> {
+        ctx.accounts.config.pending_owner = new_owner;
+
+        msg!("Ownership transfer proposed to {}", new_owner);
+
+        Ok(())
+    }
+
+    /// Complete a staged ownership rotation
+    ///
+    /// Must be signed by the `pending_owner` staged by `transfer_ownership`.
+    pub fn accept_ownership(ctx: Context<AcceptOwnership>) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.owner = config.pending_owner;
+        config.pending_owner = Pubkey::default();
+
+        msg!("Ownership transferred to {}", config.owner);
+
+        Ok(())
+    }
+
     /// Register a foreign emitter from another chain
     ///
     /// Only the owner can register emitters. Each chain can have one emitter.
@@ -64,12 +151,18 @@ pub mod message_bridge {
     /// # Arguments
     /// * `chain_id` - Wormhole chain ID of the foreign chain
     /// * `emitter_address` - Emitter address on the foreign chain (32 bytes)
-    /// * `is_default_payload` - true for default 18-byte payload (Solana/EVM), false for Aztec 50-byte payload
+    /// * `is_default_payload` - true if this emitter predates the versioned `BridgePayload`
+    ///   codec and sends a legacy fixed-width payload (18-byte default, or the Aztec layout with
+    ///   a leading txId - `BridgePayload::decode` tells these apart by length), false if it
+    ///   sends the self-describing `[payload_type][version][body]` format
+    /// * `allowed_sender` - optional allow-listed origin `sender` for this emitter;
+    ///   `[0u8; 32]` means any sender is accepted
     pub fn register_emitter(
         ctx: Context<RegisterEmitter>,
         chain_id: u16,
         emitter_address: [u8; 32],
         is_default_payload: bool,
+        allowed_sender: [u8; 32],
     ) -> Result<()> {
         // Cannot register Solana as a foreign emitter
         require!(
@@ -87,10 +180,12 @@ pub mod message_bridge {
         foreign_emitter.chain_id = chain_id;
         foreign_emitter.address = emitter_address;
         foreign_emitter.is_default_payload = is_default_payload;
+        foreign_emitter.allowed_sender = allowed_sender;
 
         msg!("Registered emitter for chain {}", chain_id);
         msg!("Address: {:?}", emitter_address);
         msg!("Is default payload: {}", is_default_payload);
+        msg!("Allowed sender: {:?}", allowed_sender);
 
         Ok(())
     }
@@ -98,11 +193,17 @@ pub mod message_bridge {
     /// Send a value to another chain via Wormhole
     ///
     /// This posts a message to Wormhole that can be relayed to the destination chain.
+    ///
+    /// Encodes as `BridgePayload::Value(ValueMessage)`, not `PayloadKind::Value`
+    /// used by `send_message` - see the `PayloadKind` doc comment in
+    /// `message.rs` for why the two payload systems are kept separate.
     pub fn send_value(
         ctx: Context<SendValue>,
         destination_chain_id: u16,
         value: u128,
     ) -> Result<()> {
+        require!(!ctx.accounts.config.paused, MessageBridgeError::BridgePaused);
+
         // Cannot send to Solana
         require!(
             destination_chain_id != SOLANA_CHAIN_ID,
@@ -148,10 +249,11 @@ pub mod message_bridge {
         }
 
         // Encode the payload
-        let payload = ValueMessage {
+        let payload = BridgePayload::Value(ValueMessage {
             destination_chain_id,
             value,
-        }
+            sender: ctx.accounts.payer.key().to_bytes(),
+        })
         .encode();
 
         // Get nonce before mutable operations
@@ -205,109 +307,68 @@ pub mod message_bridge {
     /// The VAA must be posted and verified before calling this instruction.
     /// The received message account provides replay protection.
     ///
+    /// Decodes as `BridgePayload::Value(ValueMessage)`, not the general
+    /// message bus's `PayloadKind::Value` - `ValueMessage` carries the
+    /// sender pubkey this instruction allow-lists via
+    /// `ForeignEmitter::verify_sender`, which `PayloadKind::Value` has no
+    /// room for (see the `PayloadKind` doc comment in `message.rs`).
+    ///
     /// # Arguments
     /// * `vaa_hash` - Hash of the VAA (used for verification)
     /// * `emitter_chain` - Source chain ID from the VAA
     /// * `sequence` - Sequence number from the VAA
     pub fn receive_value(
         ctx: Context<ReceiveValue>,
-        _vaa_hash: [u8; 32],
+        vaa_hash: [u8; 32],
         emitter_chain: u16,
         sequence: u64,
     ) -> Result<()> {
-        let posted_vaa = &ctx.accounts.posted_vaa;
         let current_value = &mut ctx.accounts.current_value;
         let received_message = &mut ctx.accounts.received_message;
         let config = &ctx.accounts.config;
         let foreign_emitter = &ctx.accounts.foreign_emitter;
 
-        // Parse the posted VAA account data
-        // PostedVAA layout (Borsh serialized):
-        // - 3 bytes: magic "vaa"
-        // Then MessageData (Borsh format, little-endian):
-        // - 1 byte: vaa_version (offset 3)
-        // - 1 byte: consistency_level (offset 4)
-        // - 4 bytes: vaa_time (offset 5)
-        // - 32 bytes: vaa_signature_account (offset 9)
-        // - 4 bytes: submission_time (offset 41)
-        // - 4 bytes: nonce (offset 45)
-        // - 8 bytes: sequence (offset 49)
-        // - 2 bytes: emitter_chain (offset 57)
-        // - 32 bytes: emitter_address (offset 59)
-        // - 4 bytes: payload length (offset 91)
-        // - payload data (offset 95)
-        let vaa_data = posted_vaa.try_borrow_data()?;
-
-        // Minimum size: 3 + 88 + 4 = 95 bytes (before payload data)
-        require!(vaa_data.len() >= 95, MessageBridgeError::InvalidPayload);
-
-        // Parse emitter chain (offset 57, 2 bytes, little-endian)
-        let parsed_emitter_chain = u16::from_le_bytes([vaa_data[57], vaa_data[58]]);
-
-        // Parse emitter address (offset 59, 32 bytes)
-        let mut parsed_emitter_address = [0u8; 32];
-        parsed_emitter_address.copy_from_slice(&vaa_data[59..91]);
-
-        // Parse sequence (offset 49, 8 bytes, little-endian)
-        let parsed_sequence = u64::from_le_bytes(vaa_data[49..57].try_into().unwrap());
+        require!(!config.paused, MessageBridgeError::BridgePaused);
+
+        let posted_vaa = PostedVaaV1::load(&ctx.accounts.posted_vaa, vaa_hash)?;
 
         // Verify the provided parameters match the VAA
         require!(
-            parsed_emitter_chain == emitter_chain,
+            posted_vaa.emitter_chain() == emitter_chain,
             MessageBridgeError::InvalidPayload
         );
         require!(
-            parsed_sequence == sequence,
+            posted_vaa.sequence() == sequence,
             MessageBridgeError::InvalidPayload
         );
 
         // Verify emitter is registered
         require!(
-            foreign_emitter.verify(&parsed_emitter_address),
+            foreign_emitter.verify(&posted_vaa.emitter_address()),
             MessageBridgeError::InvalidForeignEmitter
         );
 
-        // Get payload (Vec<u8> with 4-byte length prefix at offset 91)
-        // Read payload length (4 bytes LE at offset 91)
-        let payload_len = u32::from_le_bytes(vaa_data[91..95].try_into().unwrap()) as usize;
-        require!(
-            vaa_data.len() >= 95 + payload_len,
-            MessageBridgeError::InvalidPayload
-        );
-        let payload = &vaa_data[95..95 + payload_len];
-
-        // Decode the message based on registered emitter type
-        // is_default_payload: true = 18-byte (Solana/EVM), false = 50-byte (Aztec with txId)
-        let value = if foreign_emitter.is_default_payload {
-            // Default payload: [chainId(2) | value(16)]
-            require!(
-                payload.len() >= ValueMessage::PAYLOAD_SIZE,
-                MessageBridgeError::InvalidPayload
-            );
-            let msg = ValueMessage::decode(payload)?;
-
-            // Validate destination chain
-            require!(
-                msg.destination_chain_id == config.chain_id,
-                MessageBridgeError::InvalidDestinationChainId
-            );
-
-            msg.value
-        } else {
-            // Aztec payload: [txId(32) | chainId(2) | value(16)]
-            require!(
-                payload.len() >= InboundMessage::PAYLOAD_SIZE,
-                MessageBridgeError::InvalidPayload
-            );
-            let inbound = InboundMessage::decode(payload)?;
-
-            // Validate destination chain
-            require!(
-                inbound.destination_chain_id == config.chain_id,
-                MessageBridgeError::InvalidDestinationChainId
-            );
+        // Decode the message. Legacy emitters (registered before the versioned
+        // codec existed) send a fixed-width layout with no discriminator;
+        // everyone else sends the self-describing `BridgePayload` format.
+        let bridge_payload =
+            BridgePayload::decode(posted_vaa.payload()?, foreign_emitter.is_default_payload)?;
 
-            inbound.value
+        let (value, sender) = match bridge_payload {
+            BridgePayload::Value(msg) => {
+                require!(
+                    msg.destination_chain_id == config.chain_id,
+                    MessageBridgeError::InvalidDestinationChainId
+                );
+                require!(
+                    foreign_emitter.verify_sender(&msg.sender),
+                    MessageBridgeError::InvalidSender
+                );
+                (msg.value, msg.sender)
+            }
+            BridgePayload::Text(_) | BridgePayload::Batch(_) => {
+                return Err(error!(MessageBridgeError::UnsupportedPayloadKind));
+            }
         };
 
         // Update current value
@@ -318,10 +379,1120 @@ pub mod message_bridge {
         received_message.emitter_chain = emitter_chain;
         received_message.value = value;
         received_message.batch_id = 0; // Not available from raw parsing
+        received_message.sender = sender;
 
         msg!("Received value {} from chain {}", value, emitter_chain);
 
         Ok(())
     }
 
+    /// Send a batch of values to other chains in a single VAA
+    ///
+    /// Amortizes one guardian-signed VAA (and one Wormhole fee) across up to
+    /// `MAX_BATCH_ENTRIES` logical value updates.
+    pub fn send_batch(
+        ctx: Context<SendBatch>,
+        batch_id: u32,
+        entries: Vec<(u16, u128)>,
+    ) -> Result<()> {
+        require!(!entries.is_empty(), MessageBridgeError::InvalidPayload);
+        require!(
+            entries.len() <= MAX_BATCH_ENTRIES,
+            MessageBridgeError::InvalidPayload
+        );
+        require!(
+            entries
+                .iter()
+                .all(|(chain_id, _)| *chain_id != SOLANA_CHAIN_ID),
+            MessageBridgeError::InvalidDestinationChainId
+        );
+
+        let config = &mut ctx.accounts.config;
+        let wormhole_emitter = &ctx.accounts.wormhole_emitter;
+
+        // Get Wormhole fee from bridge data account and transfer to fee collector
+        {
+            let bridge_data = ctx.accounts.wormhole_bridge.try_borrow_data()?;
+            let fee = if bridge_data.len() >= 24 {
+                u64::from_le_bytes(bridge_data[16..24].try_into().unwrap_or([0u8; 8]))
+            } else {
+                0
+            };
+            drop(bridge_data);
+
+            if fee > 0 {
+                require!(
+                    ctx.accounts.payer.lamports() >= fee,
+                    MessageBridgeError::InsufficientFee
+                );
+
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                        },
+                    ),
+                    fee,
+                )?;
+            }
+        }
+
+        let entry_count = entries.len();
+
+        // Encode the payload
+        let payload = BridgePayload::Batch(BatchMessage {
+            batch_id,
+            entries: entries
+                .into_iter()
+                .map(|(destination_chain_id, value)| BatchEntry {
+                    destination_chain_id,
+                    value,
+                })
+                .collect(),
+        })
+        .encode();
+
+        let nonce = config.nonce;
+        let nonce_bytes = nonce.to_le_bytes();
+
+        let emitter_seeds: &[&[u8]] = &[
+            WormholeEmitter::SEED_PREFIX,
+            &[wormhole_emitter.bump],
+        ];
+
+        let message_bump = ctx.bumps.wormhole_message;
+        let message_seeds: &[&[u8]] = &[
+            b"message",
+            &nonce_bytes,
+            &[message_bump],
+        ];
+
+        wormhole::post_message(
+            CpiContext::new_with_signer(
+                ctx.accounts.wormhole_program.to_account_info(),
+                wormhole::PostMessage {
+                    config: ctx.accounts.wormhole_bridge.to_account_info(),
+                    message: ctx.accounts.wormhole_message.to_account_info(),
+                    emitter: ctx.accounts.wormhole_emitter.to_account_info(),
+                    sequence: ctx.accounts.wormhole_sequence.to_account_info(),
+                    payer: ctx.accounts.payer.to_account_info(),
+                    fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                &[emitter_seeds, message_seeds],
+            ),
+            nonce,
+            payload,
+            wormhole::Finality::Confirmed,
+        )?;
+
+        config.nonce += 1;
+
+        msg!("Sent batch {} ({} entries)", batch_id, entry_count);
+
+        Ok(())
+    }
+
+    /// Receive a batch of values from another chain via Wormhole
+    ///
+    /// One `ReceivedMessage` PDA is created per `(emitter_chain, sequence, index)`
+    /// for replay protection, passed in `ctx.remaining_accounts` in entry order.
+    /// `CurrentValue` is updated to the last entry addressed to this chain.
+    pub fn receive_batch(
+        ctx: Context<ReceiveBatch>,
+        vaa_hash: [u8; 32],
+        emitter_chain: u16,
+        sequence: u64,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let foreign_emitter = &ctx.accounts.foreign_emitter;
+
+        require!(!config.paused, MessageBridgeError::BridgePaused);
+
+        let (batch, parsed_emitter_chain, parsed_sequence, parsed_emitter_address) = {
+            let posted_vaa = PostedVaaV1::load(&ctx.accounts.posted_vaa, vaa_hash)?;
+
+            let bridge_payload =
+                BridgePayload::decode(posted_vaa.payload()?, foreign_emitter.is_default_payload)?;
+            let batch = match bridge_payload {
+                BridgePayload::Batch(batch) => batch,
+                _ => return Err(error!(MessageBridgeError::UnsupportedPayloadKind)),
+            };
+
+            (
+                batch,
+                posted_vaa.emitter_chain(),
+                posted_vaa.sequence(),
+                posted_vaa.emitter_address(),
+            )
+        };
+
+        require!(
+            parsed_emitter_chain == emitter_chain,
+            MessageBridgeError::InvalidPayload
+        );
+        require!(
+            parsed_sequence == sequence,
+            MessageBridgeError::InvalidPayload
+        );
+        require!(
+            foreign_emitter.verify(&parsed_emitter_address),
+            MessageBridgeError::InvalidForeignEmitter
+        );
+        require!(!batch.entries.is_empty(), MessageBridgeError::InvalidPayload);
+        require!(
+            batch.entries.len() <= MAX_BATCH_ENTRIES,
+            MessageBridgeError::InvalidPayload
+        );
+        require!(
+            ctx.remaining_accounts.len() == batch.entries.len(),
+            MessageBridgeError::InvalidPayload
+        );
+
+        let rent = Rent::get()?;
+        let mut applied_value = None;
+
+        for (index, entry) in batch.entries.iter().enumerate() {
+            let received_message_info = &ctx.remaining_accounts[index];
+            let index_bytes = (index as u8).to_le_bytes();
+            let seeds: &[&[u8]] = &[
+                ReceivedMessage::SEED_PREFIX,
+                &emitter_chain.to_le_bytes(),
+                &sequence.to_le_bytes(),
+                &index_bytes,
+            ];
+            let (expected_key, bump) = Pubkey::find_program_address(seeds, ctx.program_id);
+            require_keys_eq!(
+                expected_key,
+                received_message_info.key(),
+                MessageBridgeError::InvalidPayload
+            );
+
+            let signer_seeds: &[&[u8]] = &[
+                ReceivedMessage::SEED_PREFIX,
+                &emitter_chain.to_le_bytes(),
+                &sequence.to_le_bytes(),
+                &index_bytes,
+                &[bump],
+            ];
+
+            anchor_lang::system_program::create_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::CreateAccount {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: received_message_info.clone(),
+                    },
+                    &[signer_seeds],
+                ),
+                rent.minimum_balance(ReceivedMessage::SPACE),
+                ReceivedMessage::SPACE as u64,
+                ctx.program_id,
+            )?;
+
+            let received_message_account = ReceivedMessage {
+                sequence,
+                emitter_chain,
+                value: entry.value,
+                batch_id: batch.batch_id,
+                sender: [0u8; 32],
+            };
+
+            let mut data = received_message_info.try_borrow_mut_data()?;
+            let mut writer: &mut [u8] = &mut data;
+            received_message_account.try_serialize(&mut writer)?;
+            drop(data);
+
+            if entry.destination_chain_id == config.chain_id {
+                applied_value = Some(entry.value);
+            }
+        }
+
+        if let Some(value) = applied_value {
+            ctx.accounts.current_value.value = value;
+        }
+
+        msg!(
+            "Received batch {} from chain {} ({} entries)",
+            batch.batch_id,
+            emitter_chain,
+            batch.entries.len()
+        );
+
+        Ok(())
+    }
+
+    /// Send a value to another chain, bound to a CCTP USDC deposit-for-burn
+    ///
+    /// Burns `value` USDC from the depositor's token account via CCTP and
+    /// posts the matching `ValueMessage` VAA through the same emitter used by
+    /// `send_value`, so the destination chain can redeem both together.
+    pub fn send_value_with_cctp(
+        ctx: Context<SendValueWithCctp>,
+        destination_chain_id: u16,
+        value: u128,
+        mint_recipient: [u8; 32],
+    ) -> Result<()> {
+        require!(
+            destination_chain_id != SOLANA_CHAIN_ID,
+            MessageBridgeError::InvalidDestinationChainId
+        );
+
+        let destination_domain = ctx
+            .accounts
+            .config
+            .cctp_domain_for(destination_chain_id)
+            .ok_or(MessageBridgeError::CctpDomainNotConfigured)?;
+
+        // CCTP moves u64 lamport-denominated USDC amounts; the bridge's u128
+        // value field exists for parity with send_value's generic counter.
+        let cctp_amount: u64 = value
+            .try_into()
+            .map_err(|_| error!(MessageBridgeError::InvalidPayload))?;
+
+        cctp::deposit_for_burn(
+            &ctx.accounts.token_messenger_minter_program,
+            &ctx.accounts.message_transmitter_program,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.depositor_token_account.to_account_info(),
+            &ctx.accounts.message_transmitter,
+            &ctx.accounts.token_messenger,
+            &ctx.accounts.remote_token_messenger,
+            &ctx.accounts.token_minter,
+            &ctx.accounts.local_token,
+            &ctx.accounts.usdc_mint.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            cctp_amount,
+            destination_domain,
+            mint_recipient,
+        )?;
+
+        let config = &mut ctx.accounts.config;
+        let wormhole_emitter = &ctx.accounts.wormhole_emitter;
+
+        // Get Wormhole fee from bridge data account and transfer to fee collector
+        {
+            let bridge_data = ctx.accounts.wormhole_bridge.try_borrow_data()?;
+            let fee = if bridge_data.len() >= 24 {
+                u64::from_le_bytes(bridge_data[16..24].try_into().unwrap_or([0u8; 8]))
+            } else {
+                0
+            };
+            drop(bridge_data);
+
+            if fee > 0 {
+                require!(
+                    ctx.accounts.payer.lamports() >= fee,
+                    MessageBridgeError::InsufficientFee
+                );
+
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                        },
+                    ),
+                    fee,
+                )?;
+            }
+        }
+
+        let payload = BridgePayload::Value(ValueMessage {
+            destination_chain_id,
+            value,
+            sender: ctx.accounts.payer.key().to_bytes(),
+        })
+        .encode();
+
+        let nonce = config.nonce;
+        let nonce_bytes = nonce.to_le_bytes();
+
+        let emitter_seeds: &[&[u8]] = &[WormholeEmitter::SEED_PREFIX, &[wormhole_emitter.bump]];
+
+        let message_bump = ctx.bumps.wormhole_message;
+        let message_seeds: &[&[u8]] = &[b"message", &nonce_bytes, &[message_bump]];
+
+        wormhole::post_message(
+            CpiContext::new_with_signer(
+                ctx.accounts.wormhole_program.to_account_info(),
+                wormhole::PostMessage {
+                    config: ctx.accounts.wormhole_bridge.to_account_info(),
+                    message: ctx.accounts.wormhole_message.to_account_info(),
+                    emitter: ctx.accounts.wormhole_emitter.to_account_info(),
+                    sequence: ctx.accounts.wormhole_sequence.to_account_info(),
+                    payer: ctx.accounts.payer.to_account_info(),
+                    fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                &[emitter_seeds, message_seeds],
+            ),
+            nonce,
+            payload,
+            wormhole::Finality::Confirmed,
+        )?;
+
+        config.nonce += 1;
+
+        msg!(
+            "Sent value {} (CCTP burn) to chain {} domain {}",
+            value,
+            destination_chain_id,
+            destination_domain
+        );
+
+        Ok(())
+    }
+
+    /// Receive a value from another chain, completing the paired CCTP USDC mint
+    ///
+    /// Verifies the Wormhole VAA (replay-protected via `ReceivedMessage`, like
+    /// `receive_value`) and the CCTP mint receipt before crediting `CurrentValue`.
+    pub fn receive_value_with_cctp(
+        ctx: Context<ReceiveValueWithCctp>,
+        vaa_hash: [u8; 32],
+        emitter_chain: u16,
+        sequence: u64,
+        cctp_message: Vec<u8>,
+        cctp_attestation: Vec<u8>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let foreign_emitter = &ctx.accounts.foreign_emitter;
+
+        require!(!config.paused, MessageBridgeError::BridgePaused);
+
+        let posted_vaa = PostedVaaV1::load(&ctx.accounts.posted_vaa, vaa_hash)?;
+
+        require!(
+            posted_vaa.emitter_chain() == emitter_chain,
+            MessageBridgeError::InvalidPayload
+        );
+        require!(
+            posted_vaa.sequence() == sequence,
+            MessageBridgeError::InvalidPayload
+        );
+        require!(
+            foreign_emitter.verify(&posted_vaa.emitter_address()),
+            MessageBridgeError::InvalidForeignEmitter
+        );
+
+        let bridge_payload =
+            BridgePayload::decode(posted_vaa.payload()?, foreign_emitter.is_default_payload)?;
+        let msg = match bridge_payload {
+            BridgePayload::Value(msg) => msg,
+            _ => return Err(error!(MessageBridgeError::UnsupportedPayloadKind)),
+        };
+
+        require!(
+            msg.destination_chain_id == config.chain_id,
+            MessageBridgeError::InvalidDestinationChainId
+        );
+        require!(
+            foreign_emitter.verify_sender(&msg.sender),
+            MessageBridgeError::InvalidSender
+        );
+
+        // The CCTP burn amount is embedded in the Circle message body: a
+        // 32-byte big-endian uint256 at bytes 184..216 (116-byte generic
+        // message header, then the burn message's version/burnToken/
+        // mintRecipient fields). Cross-check it against the VAA's
+        // `msg.value` so the two can't independently claim different
+        // amounts for the same mint.
+        require!(
+            cctp_message.len() >= 216,
+            MessageBridgeError::CctpMintReceiptMismatch
+        );
+        require!(
+            cctp_message[184..200] == [0u8; 16],
+            MessageBridgeError::CctpMintReceiptMismatch
+        );
+        let cctp_amount = u128::from_be_bytes(cctp_message[200..216].try_into().unwrap());
+        require!(
+            cctp_amount == msg.value,
+            MessageBridgeError::CctpMintReceiptMismatch
+        );
+
+        drop(posted_vaa);
+
+        cctp::receive_message(
+            &ctx.accounts.message_transmitter_program,
+            &ctx.accounts.token_messenger_minter_program,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.message_transmitter,
+            &ctx.accounts.used_nonces,
+            &ctx.accounts.token_messenger,
+            &ctx.accounts.remote_token_messenger,
+            &ctx.accounts.token_minter,
+            &ctx.accounts.local_token,
+            &ctx.accounts.usdc_mint.to_account_info(),
+            &ctx.accounts.recipient_token_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            cctp_message,
+            cctp_attestation,
+        )?;
+
+        ctx.accounts.current_value.value = msg.value;
+
+        let received_message = &mut ctx.accounts.received_message;
+        received_message.sequence = sequence;
+        received_message.emitter_chain = emitter_chain;
+        received_message.value = msg.value;
+        received_message.batch_id = 0;
+        received_message.sender = msg.sender;
+
+        msg!(
+            "Received value {} (CCTP mint) from chain {}",
+            msg.value,
+            emitter_chain
+        );
+
+        Ok(())
+    }
+
+    /// Receive a human-readable text message from another chain
+    ///
+    /// Replay protection is the same `ReceivedMessage` PDA scheme used by
+    /// `receive_value`; the decoded `(nick, text)` is additionally appended to
+    /// a per-`emitter_chain` `MessageInbox` ring buffer.
+    pub fn receive_text(
+        ctx: Context<ReceiveText>,
+        vaa_hash: [u8; 32],
+        emitter_chain: u16,
+        sequence: u64,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let foreign_emitter = &ctx.accounts.foreign_emitter;
+
+        require!(!config.paused, MessageBridgeError::BridgePaused);
+
+        let text_message = {
+            let posted_vaa = PostedVaaV1::load(&ctx.accounts.posted_vaa, vaa_hash)?;
+
+            require!(
+                posted_vaa.emitter_chain() == emitter_chain,
+                MessageBridgeError::InvalidPayload
+            );
+            require!(
+                posted_vaa.sequence() == sequence,
+                MessageBridgeError::InvalidPayload
+            );
+            require!(
+                foreign_emitter.verify(&posted_vaa.emitter_address()),
+                MessageBridgeError::InvalidForeignEmitter
+            );
+
+            match BridgePayload::decode(posted_vaa.payload()?, foreign_emitter.is_default_payload)? {
+                BridgePayload::Text(text_message) => text_message,
+                _ => return Err(error!(MessageBridgeError::UnsupportedPayloadKind)),
+            }
+        };
+
+        let timestamp = ctx.accounts.clock.unix_timestamp;
+
+        let message_inbox = &mut ctx.accounts.message_inbox;
+        message_inbox.emitter_chain = emitter_chain;
+        message_inbox.push(&text_message.nick, &text_message.text, sequence, timestamp);
+
+        let received_message = &mut ctx.accounts.received_message;
+        received_message.sequence = sequence;
+        received_message.emitter_chain = emitter_chain;
+        received_message.value = 0;
+        received_message.batch_id = 0;
+        received_message.sender = [0u8; 32];
+
+        msg!(
+            "Received text from {} on chain {}: {}",
+            text_message.nick,
+            emitter_chain,
+            text_message.text
+        );
+
+        Ok(())
+    }
+
+    /// Send an arbitrary-payload message to another chain
+    ///
+    /// General-purpose counterpart to `send_value`: `payload` is Borsh-encoded
+    /// directly (see `PayloadKind`) instead of going through `BridgePayload`,
+    /// so callers can carry an open-ended `Vec<u8>`/`{nick, text}` record as
+    /// well as a plain `u128`.
+    pub fn send_message(
+        ctx: Context<SendMessage>,
+        destination_chain_id: u16,
+        payload: PayloadKind,
+    ) -> Result<()> {
+        require!(
+            destination_chain_id != SOLANA_CHAIN_ID,
+            MessageBridgeError::InvalidDestinationChainId
+        );
+
+        let config = &mut ctx.accounts.config;
+        let wormhole_emitter = &ctx.accounts.wormhole_emitter;
+
+        // Get Wormhole fee from bridge data account and transfer to fee collector
+        {
+            let bridge_data = ctx.accounts.wormhole_bridge.try_borrow_data()?;
+            let fee = if bridge_data.len() >= 24 {
+                u64::from_le_bytes(bridge_data[16..24].try_into().unwrap_or([0u8; 8]))
+            } else {
+                0
+            };
+            drop(bridge_data);
+
+            if fee > 0 {
+                require!(
+                    ctx.accounts.payer.lamports() >= fee,
+                    MessageBridgeError::InsufficientFee
+                );
+
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                        },
+                    ),
+                    fee,
+                )?;
+            }
+        }
+
+        let payload_bytes = payload
+            .try_to_vec()
+            .map_err(|_| error!(MessageBridgeError::InvalidPayload))?;
+
+        let nonce = config.nonce;
+        let nonce_bytes = nonce.to_le_bytes();
+
+        let emitter_seeds: &[&[u8]] = &[WormholeEmitter::SEED_PREFIX, &[wormhole_emitter.bump]];
+
+        let message_bump = ctx.bumps.wormhole_message;
+        let message_seeds: &[&[u8]] = &[b"message", &nonce_bytes, &[message_bump]];
+
+        wormhole::post_message(
+            CpiContext::new_with_signer(
+                ctx.accounts.wormhole_program.to_account_info(),
+                wormhole::PostMessage {
+                    config: ctx.accounts.wormhole_bridge.to_account_info(),
+                    message: ctx.accounts.wormhole_message.to_account_info(),
+                    emitter: ctx.accounts.wormhole_emitter.to_account_info(),
+                    sequence: ctx.accounts.wormhole_sequence.to_account_info(),
+                    payer: ctx.accounts.payer.to_account_info(),
+                    fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                &[emitter_seeds, message_seeds],
+            ),
+            nonce,
+            payload_bytes,
+            wormhole::Finality::Confirmed,
+        )?;
+
+        config.nonce += 1;
+
+        msg!("Sent message to chain {}", destination_chain_id);
+
+        Ok(())
+    }
+
+    /// Receive an arbitrary-payload message from another chain
+    ///
+    /// Decodes the VAA payload as a `PayloadKind` (rather than `BridgePayload`)
+    /// and stores it in `message_payload`, `realloc`'d to fit. Replay
+    /// protection is the same `ReceivedMessage` PDA scheme used elsewhere.
+    ///
+    /// Unlike `receive_value`, this is a generic store-and-forward bus: it
+    /// does not check `msg.destination_chain_id` against `config.chain_id`
+    /// or allow-list the sender via `ForeignEmitter::verify_sender`, since
+    /// `PayloadKind::Value` has no sender field to check (see the
+    /// `PayloadKind` doc comment in `message.rs`). Callers that need the
+    /// sender allow-list should keep using `send_value`/`receive_value`.
+    pub fn receive_message(
+        ctx: Context<ReceiveMessage>,
+        vaa_hash: [u8; 32],
+        emitter_chain: u16,
+        sequence: u64,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let foreign_emitter = &ctx.accounts.foreign_emitter;
+
+        require!(!config.paused, MessageBridgeError::BridgePaused);
+
+        let payload = {
+            let posted_vaa = PostedVaaV1::load(&ctx.accounts.posted_vaa, vaa_hash)?;
+
+            require!(
+                posted_vaa.emitter_chain() == emitter_chain,
+                MessageBridgeError::InvalidPayload
+            );
+            require!(
+                posted_vaa.sequence() == sequence,
+                MessageBridgeError::InvalidPayload
+            );
+            require!(
+                foreign_emitter.verify(&posted_vaa.emitter_address()),
+                MessageBridgeError::InvalidForeignEmitter
+            );
+
+            PayloadKind::try_from_slice(posted_vaa.payload()?)
+                .map_err(|_| error!(MessageBridgeError::InvalidPayload))?
+        };
+
+        let payload_bytes = payload
+            .try_to_vec()
+            .map_err(|_| error!(MessageBridgeError::InvalidPayload))?;
+        require!(
+            payload_bytes.len() <= MAX_MESSAGE_PAYLOAD_LEN,
+            MessageBridgeError::InvalidPayload
+        );
+
+        let needed_space = MessagePayload::BASE_SPACE + payload_bytes.len();
+        let message_payload_info = ctx.accounts.message_payload.to_account_info();
+        if needed_space > message_payload_info.data_len() {
+            message_payload_info.realloc(needed_space, false)?;
+
+            let rent = Rent::get()?;
+            let required_lamports = rent.minimum_balance(needed_space);
+            let shortfall = required_lamports.saturating_sub(message_payload_info.lamports());
+            if shortfall > 0 {
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: message_payload_info.clone(),
+                        },
+                    ),
+                    shortfall,
+                )?;
+            }
+        }
+
+        let message_payload = &mut ctx.accounts.message_payload;
+        message_payload.emitter_chain = emitter_chain;
+        message_payload.sequence = sequence;
+        message_payload.payload = payload;
+
+        let received_message = &mut ctx.accounts.received_message;
+        received_message.sequence = sequence;
+        received_message.emitter_chain = emitter_chain;
+        received_message.value = 0;
+        received_message.batch_id = 0;
+        received_message.sender = [0u8; 32];
+
+        msg!("Received message from chain {}", emitter_chain);
+
+        Ok(())
+    }
+
+    /// Send an SPL token to another chain via the Wormhole Token Bridge
+    ///
+    /// CPIs into the Token Bridge's `transfer_native`/`transfer_wrapped`
+    /// instruction to lock or burn `amount` of `from_token_account`; the
+    /// Token Bridge posts its own attestation VAA addressed to
+    /// `recipient`/`destination_chain_id`. Unlike `send_value`, this
+    /// instruction does not itself transfer the Wormhole fee to the fee
+    /// collector.
+    ///
+    /// # Arguments
+    /// * `destination_chain_id` - Wormhole chain ID to send to
+    /// * `amount` - amount of the token to transfer, in the mint's own units
+    /// * `recipient` - recipient address on the destination chain (32 bytes)
+    /// * `is_wrapped` - true if `mint` is a Token Bridge wrapped asset (burn), false if native (lock)
+    pub fn send_tokens(
+        ctx: Context<SendTokens>,
+        destination_chain_id: u16,
+        amount: u64,
+        recipient: [u8; 32],
+        is_wrapped: bool,
+    ) -> Result<()> {
+        require!(
+            destination_chain_id != SOLANA_CHAIN_ID,
+            MessageBridgeError::InvalidDestinationChainId
+        );
+        require_keys_eq!(
+            ctx.accounts.mint.key(),
+            ctx.accounts.from_token_account.mint,
+            MessageBridgeError::TokenBridgeMintMismatch
+        );
+
+        let (expected_authority, _) = Pubkey::find_program_address(
+            &[if is_wrapped { b"mint_signer" } else { b"custody_signer" }],
+            &ctx.accounts.token_bridge_program.key(),
+        );
+        require_keys_eq!(
+            ctx.accounts.custody_signer_or_mint_authority.key(),
+            expected_authority,
+            MessageBridgeError::TokenBridgeAuthorityMismatch
+        );
+
+        let config = &mut ctx.accounts.config;
+        let wormhole_emitter = &ctx.accounts.wormhole_emitter;
+
+        let nonce = config.nonce;
+        let nonce_bytes = nonce.to_le_bytes();
+
+        let emitter_seeds: &[&[u8]] = &[WormholeEmitter::SEED_PREFIX, &[wormhole_emitter.bump]];
+
+        let message_bump = ctx.bumps.wormhole_message;
+        let message_seeds: &[&[u8]] = &[b"message", &nonce_bytes, &[message_bump]];
+
+        token_bridge::transfer(
+            &ctx.accounts.token_bridge_program,
+            &ctx.accounts.wormhole_program.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.token_bridge_config,
+            &ctx.accounts.from_token_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.custody_or_wrapped_meta,
+            &ctx.accounts.authority_signer,
+            &ctx.accounts.custody_signer_or_mint_authority,
+            &ctx.accounts.wormhole_bridge,
+            &ctx.accounts.wormhole_message,
+            &ctx.accounts.wormhole_emitter.to_account_info(),
+            &ctx.accounts.wormhole_sequence,
+            &ctx.accounts.wormhole_fee_collector,
+            &ctx.accounts.clock.to_account_info(),
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            is_wrapped,
+            nonce,
+            amount,
+            0,
+            recipient,
+            destination_chain_id,
+            &[emitter_seeds, message_seeds],
+        )?;
+
+        config.nonce += 1;
+
+        msg!(
+            "Sent {} tokens ({}) to chain {}",
+            amount,
+            if is_wrapped { "wrapped" } else { "native" },
+            destination_chain_id
+        );
+
+        Ok(())
+    }
+
+    /// Complete a Wormhole Token Bridge transfer into a recipient ATA
+    ///
+    /// Verifies our own VAA (replay-protected via `ReceivedMessage`, like
+    /// `receive_value`) before asking the Token Bridge to complete its
+    /// companion transfer VAA, which releases or mints the token.
+    ///
+    /// # Arguments
+    /// * `is_wrapped` - true if the Token Bridge should mint a wrapped asset, false if it should release a native one
+    pub fn redeem_tokens(
+        ctx: Context<RedeemTokens>,
+        vaa_hash: [u8; 32],
+        emitter_chain: u16,
+        sequence: u64,
+        is_wrapped: bool,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let foreign_emitter = &ctx.accounts.foreign_emitter;
+
+        require!(!config.paused, MessageBridgeError::BridgePaused);
+
+        {
+            let posted_vaa = PostedVaaV1::load(&ctx.accounts.posted_vaa, vaa_hash)?;
+
+            require!(
+                posted_vaa.emitter_chain() == emitter_chain,
+                MessageBridgeError::InvalidPayload
+            );
+            require!(
+                posted_vaa.sequence() == sequence,
+                MessageBridgeError::InvalidPayload
+            );
+            require!(
+                foreign_emitter.verify(&posted_vaa.emitter_address()),
+                MessageBridgeError::InvalidForeignEmitter
+            );
+        }
+
+        let (expected_authority, _) = Pubkey::find_program_address(
+            &[if is_wrapped { b"mint_signer" } else { b"custody_signer" }],
+            &ctx.accounts.token_bridge_program.key(),
+        );
+        require_keys_eq!(
+            ctx.accounts.custody_signer_or_mint_authority.key(),
+            expected_authority,
+            MessageBridgeError::TokenBridgeAuthorityMismatch
+        );
+
+        token_bridge::complete_transfer(
+            &ctx.accounts.token_bridge_program,
+            &ctx.accounts.wormhole_program.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.token_bridge_config,
+            &ctx.accounts.token_bridge_posted_vaa,
+            &ctx.accounts.token_bridge_claim,
+            &ctx.accounts.token_bridge_foreign_endpoint,
+            &ctx.accounts.recipient_token_account.to_account_info(),
+            &ctx.accounts.fee_recipient_token_account.to_account_info(),
+            &ctx.accounts.custody_or_wrapped_mint,
+            &ctx.accounts.custody_signer_or_mint_authority,
+            &ctx.accounts.rent.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            is_wrapped,
+        )?;
+
+        let received_message = &mut ctx.accounts.received_message;
+        received_message.sequence = sequence;
+        received_message.emitter_chain = emitter_chain;
+        received_message.value = 0;
+        received_message.batch_id = 0;
+        received_message.sender = [0u8; 32];
+
+        msg!("Redeemed tokens from chain {}", emitter_chain);
+
+        Ok(())
+    }
+
+    /// Burn USDC natively via Circle CCTP, pairing it with a Wormhole envelope
+    ///
+    /// CPIs into the Token Messenger Minter's `deposit_for_burn_with_caller`
+    /// (restricting who may complete the mint to `destination_caller`),
+    /// then posts a `CctpTransferEnvelope` carrying the resulting CCTP nonce
+    /// and source domain so the recipient can correlate the two messages.
+    ///
+    /// # Arguments
+    /// * `destination_chain_id` - Wormhole chain ID to send to
+    /// * `amount` - amount of USDC to burn, in its native 6-decimal units
+    /// * `mint_recipient` - recipient address on the destination domain (32 bytes)
+    /// * `destination_caller` - address on the destination domain allowed to complete the mint (32 bytes)
+    pub fn transfer_usdc(
+        ctx: Context<TransferUsdc>,
+        destination_chain_id: u16,
+        amount: u64,
+        mint_recipient: [u8; 32],
+        destination_caller: [u8; 32],
+    ) -> Result<()> {
+        require!(
+            destination_chain_id != SOLANA_CHAIN_ID,
+            MessageBridgeError::InvalidDestinationChainId
+        );
+
+        let destination_domain = ctx
+            .accounts
+            .config
+            .cctp_domain_for(destination_chain_id)
+            .ok_or(MessageBridgeError::CctpDomainNotConfigured)?;
+
+        let cctp_nonce = cctp::deposit_for_burn_with_caller(
+            &ctx.accounts.token_messenger_minter_program,
+            &ctx.accounts.message_transmitter_program,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.depositor_token_account.to_account_info(),
+            &ctx.accounts.message_transmitter,
+            &ctx.accounts.token_messenger,
+            &ctx.accounts.remote_token_messenger,
+            &ctx.accounts.token_minter,
+            &ctx.accounts.local_token,
+            &ctx.accounts.usdc_mint.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            amount,
+            destination_domain,
+            mint_recipient,
+            destination_caller,
+        )?;
+
+        let config = &mut ctx.accounts.config;
+        let wormhole_emitter = &ctx.accounts.wormhole_emitter;
+
+        // Get Wormhole fee from bridge data account and transfer to fee collector
+        {
+            let bridge_data = ctx.accounts.wormhole_bridge.try_borrow_data()?;
+            let fee = if bridge_data.len() >= 24 {
+                u64::from_le_bytes(bridge_data[16..24].try_into().unwrap_or([0u8; 8]))
+            } else {
+                0
+            };
+            drop(bridge_data);
+
+            if fee > 0 {
+                require!(
+                    ctx.accounts.payer.lamports() >= fee,
+                    MessageBridgeError::InsufficientFee
+                );
+
+                anchor_lang::system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        anchor_lang::system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                        },
+                    ),
+                    fee,
+                )?;
+            }
+        }
+
+        // Solana's own CCTP domain is registered in the same chain-id -> domain
+        // map used for destinations, keyed by our own `SOLANA_CHAIN_ID`.
+        let source_domain = config
+            .cctp_domain_for(SOLANA_CHAIN_ID)
+            .ok_or(MessageBridgeError::CctpDomainNotConfigured)?;
+
+        let payload = BridgePayload::CctpTransfer(CctpTransferEnvelope {
+            cctp_nonce,
+            source_domain,
+            value: amount as u128,
+            destination_chain_id,
+        })
+        .encode();
+
+        let nonce = config.nonce;
+        let nonce_bytes = nonce.to_le_bytes();
+
+        let emitter_seeds: &[&[u8]] = &[WormholeEmitter::SEED_PREFIX, &[wormhole_emitter.bump]];
+
+        let message_bump = ctx.bumps.wormhole_message;
+        let message_seeds: &[&[u8]] = &[b"message", &nonce_bytes, &[message_bump]];
+
+        wormhole::post_message(
+            CpiContext::new_with_signer(
+                ctx.accounts.wormhole_program.to_account_info(),
+                wormhole::PostMessage {
+                    config: ctx.accounts.wormhole_bridge.to_account_info(),
+                    message: ctx.accounts.wormhole_message.to_account_info(),
+                    emitter: ctx.accounts.wormhole_emitter.to_account_info(),
+                    sequence: ctx.accounts.wormhole_sequence.to_account_info(),
+                    payer: ctx.accounts.payer.to_account_info(),
+                    fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                &[emitter_seeds, message_seeds],
+            ),
+            nonce,
+            payload,
+            wormhole::Finality::Confirmed,
+        )?;
+
+        config.nonce += 1;
+
+        msg!(
+            "Burned {} USDC (CCTP nonce {}) to chain {} domain {}",
+            amount,
+            cctp_nonce,
+            destination_chain_id,
+            destination_domain
+        );
+
+        Ok(())
+    }
+
+    /// Redeem a `transfer_usdc` envelope, completing the paired CCTP mint
+    ///
+    /// Verifies our own VAA (replay-protected via `ReceivedMessage`, like
+    /// `receive_value_with_cctp`) and that its `CctpTransferEnvelope` nonce
+    /// matches `circle_message`, then completes the CCTP `receive_message`.
+    pub fn redeem_usdc(
+        ctx: Context<RedeemUsdc>,
+        vaa_hash: [u8; 32],
+        emitter_chain: u16,
+        sequence: u64,
+        circle_message: Vec<u8>,
+        attestation: Vec<u8>,
+    ) -> Result<()> {
+        let config = &ctx.accounts.config;
+        let foreign_emitter = &ctx.accounts.foreign_emitter;
+
+        require!(!config.paused, MessageBridgeError::BridgePaused);
+
+        let posted_vaa = PostedVaaV1::load(&ctx.accounts.posted_vaa, vaa_hash)?;
+
+        require!(
+            posted_vaa.emitter_chain() == emitter_chain,
+            MessageBridgeError::InvalidPayload
+        );
+        require!(
+            posted_vaa.sequence() == sequence,
+            MessageBridgeError::InvalidPayload
+        );
+        require!(
+            foreign_emitter.verify(&posted_vaa.emitter_address()),
+            MessageBridgeError::InvalidForeignEmitter
+        );
+
+        let bridge_payload =
+            BridgePayload::decode(posted_vaa.payload()?, foreign_emitter.is_default_payload)?;
+        let envelope = match bridge_payload {
+            BridgePayload::CctpTransfer(envelope) => envelope,
+            _ => return Err(error!(MessageBridgeError::UnsupportedPayloadKind)),
+        };
+
+        // The burn's CCTP nonce is embedded in the Circle message body (bytes
+        // 12..20, big-endian) - cross-check it against the nonce carried in
+        // our own Wormhole envelope before minting.
+        require!(
+            circle_message.len() >= 20,
+            MessageBridgeError::CctpNonceMismatch
+        );
+        let circle_nonce = u64::from_be_bytes(circle_message[12..20].try_into().unwrap());
+        require!(
+            circle_nonce == envelope.cctp_nonce,
+            MessageBridgeError::CctpNonceMismatch
+        );
+
+        drop(posted_vaa);
+
+        cctp::receive_message(
+            &ctx.accounts.message_transmitter_program,
+            &ctx.accounts.token_messenger_minter_program,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.message_transmitter,
+            &ctx.accounts.used_nonces,
+            &ctx.accounts.token_messenger,
+            &ctx.accounts.remote_token_messenger,
+            &ctx.accounts.token_minter,
+            &ctx.accounts.local_token,
+            &ctx.accounts.usdc_mint.to_account_info(),
+            &ctx.accounts.recipient_token_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            circle_message,
+            attestation,
+        )?;
+
+        ctx.accounts.current_value.value = envelope.value;
+
+        let received_message = &mut ctx.accounts.received_message;
+        received_message.sequence = sequence;
+        received_message.emitter_chain = emitter_chain;
+        received_message.value = envelope.value;
+        received_message.batch_id = 0;
+        received_message.sender = [0u8; 32];
+
+        msg!(
+            "Redeemed {} USDC (CCTP nonce {}) from chain {}",
+            envelope.value,
+            envelope.cctp_nonce,
+            emitter_chain
+        );
+
+        Ok(())
+    }
+
 }
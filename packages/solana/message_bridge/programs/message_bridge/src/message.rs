@@ -1,84 +1,244 @@
 use anchor_lang::prelude::*;
-use std::io::{self, Write};
+use std::io::{self, Read, Write};
 use wormhole_io::{Readable, Writeable};
 
+use crate::error::MessageBridgeError;
+
+/// Read a `T: Readable` from `data` and require that every byte of `data` was
+/// consumed, rejecting both short payloads (propagated as an `io::Error` from
+/// `T::read`) and padded/extended ones (extra trailing bytes).
+fn decode_exact<T: Readable>(data: &[u8]) -> Result<T> {
+    let mut cursor = io::Cursor::new(data);
+    let value = T::read(&mut cursor).map_err(|_| error!(MessageBridgeError::InvalidPayload))?;
+    require_eq!(
+        cursor.position() as usize,
+        data.len(),
+        MessageBridgeError::InvalidPayload
+    );
+    Ok(value)
+}
+
+/// Encode a `T: Writeable` into a freshly allocated buffer.
+fn encode_writeable<T: Writeable>(value: &T) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(value.written_size());
+    value
+        .write(&mut buf)
+        .expect("writing to a Vec<u8> cannot fail");
+    buf
+}
+
 /// Message payload for cross-chain value transfer
 ///
-/// Outbound format (Solana -> other chains):
-///   - destination_chain_id: u16 (2 bytes, big-endian)
-///   - value: u128 (16 bytes, big-endian)
-///   Total: 18 bytes
-///
-/// Inbound format (other chains -> Solana, after guardian adds txId):
-///   - tx_id: [u8; 32] (32 bytes, added by guardian)
+/// Outbound format (Solana -> other chains, current codec):
 ///   - destination_chain_id: u16 (2 bytes, big-endian)
 ///   - value: u128 (16 bytes, big-endian)
+///   - sender: [u8; 32] (origin account that requested the transfer)
 ///   Total: 50 bytes
+///
+/// Legacy fixed-width formats (pre-dating the `sender` field and the
+/// versioned `BridgePayload` codec) are parsed separately by
+/// `BridgePayload::decode_legacy_value`; they carry no sender, so it is
+/// reported as `[0u8; 32]` (default 18-byte layout) or backfilled from
+/// `InboundMessage::sender` (Aztec layout).
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ValueMessage {
     pub destination_chain_id: u16,
     pub value: u128,
+    pub sender: [u8; 32],
 }
 
 impl ValueMessage {
-    pub const PAYLOAD_SIZE: usize = 18; // 2 + 16
+    pub const PAYLOAD_SIZE: usize = 2 + 16 + 32;
+    pub const PAYLOAD_TYPE: u8 = 0;
 
     /// Encode message for outbound transfer
     pub fn encode(&self) -> Vec<u8> {
-        let mut buf = Vec::with_capacity(Self::PAYLOAD_SIZE);
-        buf.extend_from_slice(&self.destination_chain_id.to_be_bytes());
-        buf.extend_from_slice(&self.value.to_be_bytes());
-        buf
+        encode_writeable(self)
     }
 
-    /// Decode message from inbound payload (without txId prefix)
+    /// Decode message from inbound payload, rejecting anything but an exact
+    /// `PAYLOAD_SIZE`-byte match.
     pub fn decode(data: &[u8]) -> Result<Self> {
-        if data.len() < Self::PAYLOAD_SIZE {
-            return Err(error!(crate::error::MessageBridgeError::InvalidPayload));
-        }
+        decode_exact(data)
+    }
+}
+
+impl Writeable for ValueMessage {
+    fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.destination_chain_id.to_be_bytes())?;
+        writer.write_all(&self.value.to_be_bytes())?;
+        writer.write_all(&self.sender)?;
+        Ok(())
+    }
+
+    fn written_size(&self) -> usize {
+        Self::PAYLOAD_SIZE
+    }
+}
+
+impl Readable for ValueMessage {
+    const SIZE: Option<usize> = Some(Self::PAYLOAD_SIZE);
 
-        let destination_chain_id = u16::from_be_bytes([data[0], data[1]]);
-        let value = u128::from_be_bytes(data[2..18].try_into().unwrap());
+    fn read<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut chain_bytes = [0u8; 2];
+        reader.read_exact(&mut chain_bytes)?;
+        let destination_chain_id = u16::from_be_bytes(chain_bytes);
+
+        let mut value_bytes = [0u8; 16];
+        reader.read_exact(&mut value_bytes)?;
+        let value = u128::from_be_bytes(value_bytes);
+
+        let mut sender = [0u8; 32];
+        reader.read_exact(&mut sender)?;
 
         Ok(Self {
             destination_chain_id,
             value,
+            sender,
         })
     }
 }
 
-/// Inbound message with txId (from Aztec Guardian)
+/// Inbound message with txId and sender (from Aztec Guardian)
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct InboundMessage {
     pub tx_id: [u8; 32],
+    pub sender: [u8; 32],
     pub destination_chain_id: u16,
     pub value: u128,
 }
 
 impl InboundMessage {
-    pub const PAYLOAD_SIZE: usize = 50; // 32 + 2 + 16
+    pub const PAYLOAD_SIZE: usize = 32 + 32 + 2 + 16;
 
-    /// Decode inbound message with txId prefix
+    /// Decode inbound message with txId and sender prefix, rejecting anything
+    /// but an exact `PAYLOAD_SIZE`-byte match.
     pub fn decode(data: &[u8]) -> Result<Self> {
-        if data.len() < Self::PAYLOAD_SIZE {
-            return Err(error!(crate::error::MessageBridgeError::InvalidPayload));
-        }
+        decode_exact(data)
+    }
+}
+
+impl Writeable for InboundMessage {
+    fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.tx_id)?;
+        writer.write_all(&self.sender)?;
+        writer.write_all(&self.destination_chain_id.to_be_bytes())?;
+        writer.write_all(&self.value.to_be_bytes())?;
+        Ok(())
+    }
 
+    fn written_size(&self) -> usize {
+        Self::PAYLOAD_SIZE
+    }
+}
+
+impl Readable for InboundMessage {
+    const SIZE: Option<usize> = Some(Self::PAYLOAD_SIZE);
+
+    fn read<R: Read>(reader: &mut R) -> io::Result<Self> {
         let mut tx_id = [0u8; 32];
-        tx_id.copy_from_slice(&data[0..32]);
+        reader.read_exact(&mut tx_id)?;
 
-        let destination_chain_id = u16::from_be_bytes([data[32], data[33]]);
-        let value = u128::from_be_bytes(data[34..50].try_into().unwrap());
+        let mut sender = [0u8; 32];
+        reader.read_exact(&mut sender)?;
+
+        let mut chain_bytes = [0u8; 2];
+        reader.read_exact(&mut chain_bytes)?;
+        let destination_chain_id = u16::from_be_bytes(chain_bytes);
+
+        let mut value_bytes = [0u8; 16];
+        reader.read_exact(&mut value_bytes)?;
+        let value = u128::from_be_bytes(value_bytes);
 
         Ok(Self {
             tx_id,
+            sender,
             destination_chain_id,
             value,
         })
     }
 }
 
-impl Writeable for ValueMessage {
+/// Human-readable text message (nick + text), delivered cross-chain
+///
+/// Encoded as a `u16` length prefix followed by UTF-8 bytes, for each field
+/// in turn (nick, then text).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextMessage {
+    pub nick: String,
+    pub text: String,
+}
+
+impl TextMessage {
+    pub const PAYLOAD_TYPE: u8 = 1;
+
+    pub fn encode(&self) -> Vec<u8> {
+        encode_writeable(self)
+    }
+
+    /// Decode, rejecting short/truncated payloads as well as any trailing
+    /// bytes left over once both strings have been read.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        decode_exact(data)
+    }
+
+    fn write_string<W: Write>(writer: &mut W, value: &str) -> io::Result<()> {
+        writer.write_all(&(value.len() as u16).to_be_bytes())?;
+        writer.write_all(value.as_bytes())
+    }
+
+    fn read_string<R: Read>(reader: &mut R, max_len: usize) -> io::Result<String> {
+        let mut len_bytes = [0u8; 2];
+        reader.read_exact(&mut len_bytes)?;
+        let len = u16::from_be_bytes(len_bytes) as usize;
+        if len > max_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "string exceeds maximum length",
+            ));
+        }
+
+        let mut bytes = vec![0u8; len];
+        reader.read_exact(&mut bytes)?;
+        String::from_utf8(bytes)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "string is not valid UTF-8"))
+    }
+}
+
+impl Writeable for TextMessage {
+    fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        Self::write_string(writer, &self.nick)?;
+        Self::write_string(writer, &self.text)?;
+        Ok(())
+    }
+
+    fn written_size(&self) -> usize {
+        2 + self.nick.len() + 2 + self.text.len()
+    }
+}
+
+impl Readable for TextMessage {
+    const SIZE: Option<usize> = None;
+
+    fn read<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let nick = Self::read_string(reader, crate::state::MAX_NICK_LEN)?;
+        let text = Self::read_string(reader, crate::state::MAX_TEXT_LEN)?;
+        Ok(Self { nick, text })
+    }
+}
+
+/// One entry in a batched value transfer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchEntry {
+    pub destination_chain_id: u16,
+    pub value: u128,
+}
+
+impl BatchEntry {
+    pub const SIZE: usize = 2 + 16;
+}
+
+impl Writeable for BatchEntry {
     fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
         writer.write_all(&self.destination_chain_id.to_be_bytes())?;
         writer.write_all(&self.value.to_be_bytes())?;
@@ -86,14 +246,14 @@ impl Writeable for ValueMessage {
     }
 
     fn written_size(&self) -> usize {
-        Self::PAYLOAD_SIZE
+        Self::SIZE
     }
 }
 
-impl Readable for ValueMessage {
-    const SIZE: Option<usize> = Some(Self::PAYLOAD_SIZE);
+impl Readable for BatchEntry {
+    const SIZE: Option<usize> = Some(Self::SIZE);
 
-    fn read<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+    fn read<R: Read>(reader: &mut R) -> io::Result<Self> {
         let mut chain_bytes = [0u8; 2];
         reader.read_exact(&mut chain_bytes)?;
         let destination_chain_id = u16::from_be_bytes(chain_bytes);
@@ -108,3 +268,496 @@ impl Readable for ValueMessage {
         })
     }
 }
+
+/// Maximum number of entries in a single `BatchMessage`, chosen so the
+/// encoded payload comfortably fits Wormhole's message size limit.
+pub const MAX_BATCH_ENTRIES: usize = 16;
+
+/// Multiple value transfers packed into a single VAA, sharing one `batch_id`
+///
+/// Format: `[batch_id: u32][count: u8][(destination_chain_id: u16, value: u128); count]`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchMessage {
+    pub batch_id: u32,
+    pub entries: Vec<BatchEntry>,
+}
+
+impl BatchMessage {
+    pub const PAYLOAD_TYPE: u8 = 2;
+
+    pub fn encode(&self) -> Vec<u8> {
+        encode_writeable(self)
+    }
+
+    /// Decode, rejecting an empty or over-long entry list as well as any
+    /// length mismatch between the declared `count` and the remaining bytes.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        decode_exact(data)
+    }
+}
+
+impl Writeable for BatchMessage {
+    fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.batch_id.to_be_bytes())?;
+        writer.write_all(&[self.entries.len() as u8])?;
+        for entry in &self.entries {
+            entry.write(writer)?;
+        }
+        Ok(())
+    }
+
+    fn written_size(&self) -> usize {
+        4 + 1 + self.entries.len() * BatchEntry::SIZE
+    }
+}
+
+impl Readable for BatchMessage {
+    const SIZE: Option<usize> = None;
+
+    fn read<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut batch_id_bytes = [0u8; 4];
+        reader.read_exact(&mut batch_id_bytes)?;
+        let batch_id = u32::from_be_bytes(batch_id_bytes);
+
+        let mut count_byte = [0u8; 1];
+        reader.read_exact(&mut count_byte)?;
+        let count = count_byte[0] as usize;
+
+        if count == 0 || count > MAX_BATCH_ENTRIES {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "batch entry count out of range",
+            ));
+        }
+
+        let mut entries = Vec::with_capacity(count);
+        for _ in 0..count {
+            entries.push(BatchEntry::read(reader)?);
+        }
+
+        Ok(Self { batch_id, entries })
+    }
+}
+
+/// Routing/ordering envelope paired with a Circle CCTP `deposit_for_burn_with_caller`,
+/// carried as our own Wormhole message alongside the native USDC burn-and-mint
+/// (`transfer_usdc`/`redeem_usdc`) - lets the recipient correlate the CCTP
+/// attestation with the Wormhole VAA before minting.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CctpTransferEnvelope {
+    pub cctp_nonce: u64,
+    pub source_domain: u32,
+    pub value: u128,
+    pub destination_chain_id: u16,
+}
+
+impl CctpTransferEnvelope {
+    pub const PAYLOAD_SIZE: usize = 8 + 4 + 16 + 2;
+    pub const PAYLOAD_TYPE: u8 = 3;
+
+    pub fn encode(&self) -> Vec<u8> {
+        encode_writeable(self)
+    }
+
+    /// Decode message from inbound payload, rejecting anything but an exact
+    /// `PAYLOAD_SIZE`-byte match.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        decode_exact(data)
+    }
+}
+
+impl Writeable for CctpTransferEnvelope {
+    fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.cctp_nonce.to_be_bytes())?;
+        writer.write_all(&self.source_domain.to_be_bytes())?;
+        writer.write_all(&self.value.to_be_bytes())?;
+        writer.write_all(&self.destination_chain_id.to_be_bytes())?;
+        Ok(())
+    }
+
+    fn written_size(&self) -> usize {
+        Self::PAYLOAD_SIZE
+    }
+}
+
+impl Readable for CctpTransferEnvelope {
+    const SIZE: Option<usize> = Some(Self::PAYLOAD_SIZE);
+
+    fn read<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut nonce_bytes = [0u8; 8];
+        reader.read_exact(&mut nonce_bytes)?;
+        let cctp_nonce = u64::from_be_bytes(nonce_bytes);
+
+        let mut domain_bytes = [0u8; 4];
+        reader.read_exact(&mut domain_bytes)?;
+        let source_domain = u32::from_be_bytes(domain_bytes);
+
+        let mut value_bytes = [0u8; 16];
+        reader.read_exact(&mut value_bytes)?;
+        let value = u128::from_be_bytes(value_bytes);
+
+        let mut chain_bytes = [0u8; 2];
+        reader.read_exact(&mut chain_bytes)?;
+        let destination_chain_id = u16::from_be_bytes(chain_bytes);
+
+        Ok(Self {
+            cctp_nonce,
+            source_domain,
+            value,
+            destination_chain_id,
+        })
+    }
+}
+
+/// Arbitrary cross-chain message payload for the general-purpose message bus
+/// (`send_message`/`receive_message`)
+///
+/// Borsh-encodes directly (variant index as a one-byte discriminator,
+/// followed by the variant's fields) rather than going through
+/// `BridgePayload`'s `[payload_type][version][body]` framing - this is a
+/// parallel wire format for instructions that want to carry an open-ended
+/// `Vec<u8>`/`String` rather than the fixed `ValueMessage`/`BatchMessage`
+/// layouts. `Value` exists here so callers of the general bus can express
+/// the same single-`u128` update `send_value` does, but it is deliberately
+/// *not* the same wire type as `BridgePayload::Value(ValueMessage)`:
+/// `ValueMessage` also carries the original sender's pubkey, which
+/// `receive_value` checks against `ForeignEmitter::verify_sender` as a
+/// per-message allow-list, while `receive_message` trusts any payload from
+/// a registered emitter and just stores it. Collapsing `PayloadKind::Value`
+/// onto `ValueMessage` would either drop that sender check or silently
+/// start enforcing it on the general bus, so `send_value`/`receive_value`
+/// are intentionally left on `BridgePayload` rather than migrated here.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum PayloadKind {
+    Value(u128),
+    Raw(Vec<u8>),
+    Text { nick: String, text: String },
+}
+
+/// Version byte written after the payload-type discriminator
+pub const PAYLOAD_VERSION: u8 = 1;
+
+/// Self-describing, versioned outbound/inbound VAA payload
+///
+/// New messages are encoded as `[payload_type: u8][version: u8][body]`, so the
+/// bridge can carry several message kinds from the same registered emitter
+/// instead of relying on a fixed per-emitter layout.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BridgePayload {
+    Value(ValueMessage),
+    Text(TextMessage),
+    Batch(BatchMessage),
+    CctpTransfer(CctpTransferEnvelope),
+}
+
+impl BridgePayload {
+    /// Decode a VAA payload.
+    ///
+    /// `legacy` is set from `ForeignEmitter::is_default_payload` for emitters
+    /// registered before the versioned codec existed: their payloads carry no
+    /// discriminator at all, so they are parsed with the original fixed-width
+    /// `ValueMessage`/`InboundMessage` layouts and always resolve to `Value`.
+    pub fn decode(data: &[u8], legacy: bool) -> Result<Self> {
+        if legacy {
+            return Self::decode_legacy_value(data).map(BridgePayload::Value);
+        }
+
+        require!(data.len() >= 2, MessageBridgeError::InvalidPayload);
+        let payload_type = data[0];
+        let version = data[1];
+        require!(version == PAYLOAD_VERSION, MessageBridgeError::InvalidPayload);
+
+        let body = &data[2..];
+        match payload_type {
+            ValueMessage::PAYLOAD_TYPE => Ok(BridgePayload::Value(ValueMessage::decode(body)?)),
+            TextMessage::PAYLOAD_TYPE => Ok(BridgePayload::Text(TextMessage::decode(body)?)),
+            BatchMessage::PAYLOAD_TYPE => Ok(BridgePayload::Batch(BatchMessage::decode(body)?)),
+            CctpTransferEnvelope::PAYLOAD_TYPE => Ok(BridgePayload::CctpTransfer(
+                CctpTransferEnvelope::decode(body)?,
+            )),
+            _ => Err(error!(MessageBridgeError::InvalidPayload)),
+        }
+    }
+
+    /// Legacy 18-byte default layout: `[chain_id(2) | value(16)]`, with no
+    /// room for a sender.
+    const LEGACY_DEFAULT_SIZE: usize = 2 + 16;
+
+    /// Parse the pre-codec fixed-width layouts: the 18-byte default layout
+    /// (no sender available), or the Aztec layout carrying a leading `tx_id`
+    /// and `sender`.
+    fn decode_legacy_value(data: &[u8]) -> Result<ValueMessage> {
+        match data.len() {
+            Self::LEGACY_DEFAULT_SIZE => {
+                let destination_chain_id = u16::from_be_bytes([data[0], data[1]]);
+                let value = u128::from_be_bytes(data[2..18].try_into().unwrap());
+                Ok(ValueMessage {
+                    destination_chain_id,
+                    value,
+                    sender: [0u8; 32],
+                })
+            }
+            InboundMessage::PAYLOAD_SIZE => {
+                let inbound = InboundMessage::decode(data)?;
+                Ok(ValueMessage {
+                    destination_chain_id: inbound.destination_chain_id,
+                    value: inbound.value,
+                    sender: inbound.sender,
+                })
+            }
+            _ => Err(error!(MessageBridgeError::InvalidPayload)),
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let (payload_type, mut body) = match self {
+            BridgePayload::Value(msg) => (ValueMessage::PAYLOAD_TYPE, msg.encode()),
+            BridgePayload::Text(msg) => (TextMessage::PAYLOAD_TYPE, msg.encode()),
+            BridgePayload::Batch(msg) => (BatchMessage::PAYLOAD_TYPE, msg.encode()),
+            BridgePayload::CctpTransfer(msg) => (CctpTransferEnvelope::PAYLOAD_TYPE, msg.encode()),
+        };
+
+        let mut buf = Vec::with_capacity(2 + body.len());
+        buf.push(payload_type);
+        buf.push(PAYLOAD_VERSION);
+        buf.append(&mut body);
+        buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value_message() -> ValueMessage {
+        ValueMessage {
+            destination_chain_id: 2,
+            value: u128::MAX,
+            sender: [7u8; 32],
+        }
+    }
+
+    #[test]
+    fn value_message_round_trips_through_encode_decode() {
+        let msg = value_message();
+        assert_eq!(ValueMessage::decode(&msg.encode()).unwrap(), msg);
+    }
+
+    #[test]
+    fn value_message_round_trips_through_write_read() {
+        let msg = value_message();
+        let mut buf = Vec::new();
+        msg.write(&mut buf).unwrap();
+        assert_eq!(buf.len(), ValueMessage::PAYLOAD_SIZE);
+        let mut cursor = io::Cursor::new(buf.as_slice());
+        assert_eq!(ValueMessage::read(&mut cursor).unwrap(), msg);
+    }
+
+    #[test]
+    fn value_message_rejects_short_and_padded_payloads() {
+        let encoded = value_message().encode();
+        assert!(ValueMessage::decode(&encoded[..encoded.len() - 1]).is_err());
+
+        let mut padded = encoded.clone();
+        padded.push(0);
+        assert!(ValueMessage::decode(&padded).is_err());
+    }
+
+    #[test]
+    fn inbound_message_round_trips_and_strips_tx_id_prefix() {
+        let msg = InboundMessage {
+            tx_id: [1u8; 32],
+            sender: [2u8; 32],
+            destination_chain_id: 1,
+            value: 42,
+        };
+        let mut buf = Vec::new();
+        msg.write(&mut buf).unwrap();
+        assert_eq!(buf.len(), InboundMessage::PAYLOAD_SIZE);
+
+        // The first 32 bytes are the tx_id prefix; decoding strips it off into
+        // its own field rather than folding it into `sender`.
+        assert_eq!(&buf[..32], &msg.tx_id[..]);
+        assert_eq!(&buf[32..64], &msg.sender[..]);
+
+        let decoded = InboundMessage::decode(&buf).unwrap();
+        assert_eq!(decoded, msg);
+    }
+
+    #[test]
+    fn inbound_message_rejects_wrong_length() {
+        let msg = InboundMessage {
+            tx_id: [0u8; 32],
+            sender: [0u8; 32],
+            destination_chain_id: 1,
+            value: 1,
+        };
+        let mut buf = Vec::new();
+        msg.write(&mut buf).unwrap();
+        buf.truncate(buf.len() - 1);
+        assert!(InboundMessage::decode(&buf).is_err());
+    }
+
+    #[test]
+    fn text_message_round_trips_at_boundary_lengths() {
+        let msg = TextMessage {
+            nick: "a".repeat(crate::state::MAX_NICK_LEN),
+            text: "b".repeat(crate::state::MAX_TEXT_LEN),
+        };
+        let encoded = msg.encode();
+        assert_eq!(TextMessage::decode(&encoded).unwrap(), msg);
+
+        let empty = TextMessage {
+            nick: String::new(),
+            text: String::new(),
+        };
+        assert_eq!(TextMessage::decode(&empty.encode()).unwrap(), empty);
+    }
+
+    #[test]
+    fn text_message_rejects_oversized_fields_and_trailing_bytes() {
+        let too_long = TextMessage {
+            nick: "a".repeat(crate::state::MAX_NICK_LEN + 1),
+            text: "hi".to_string(),
+        };
+        assert!(TextMessage::decode(&too_long.encode()).is_err());
+
+        let msg = TextMessage {
+            nick: "nick".to_string(),
+            text: "hello".to_string(),
+        };
+        let mut padded = msg.encode();
+        padded.push(0xff);
+        assert!(TextMessage::decode(&padded).is_err());
+    }
+
+    #[test]
+    fn batch_message_round_trips_at_boundary_counts() {
+        let single = BatchMessage {
+            batch_id: 1,
+            entries: vec![BatchEntry {
+                destination_chain_id: 2,
+                value: 100,
+            }],
+        };
+        assert_eq!(BatchMessage::decode(&single.encode()).unwrap(), single);
+
+        let full = BatchMessage {
+            batch_id: 2,
+            entries: (0..MAX_BATCH_ENTRIES as u16)
+                .map(|i| BatchEntry {
+                    destination_chain_id: i,
+                    value: i as u128,
+                })
+                .collect(),
+        };
+        assert_eq!(BatchMessage::decode(&full.encode()).unwrap(), full);
+    }
+
+    #[test]
+    fn batch_message_rejects_empty_and_trailing_bytes() {
+        let empty = BatchMessage {
+            batch_id: 1,
+            entries: vec![],
+        };
+        assert!(BatchMessage::decode(&empty.encode()).is_err());
+
+        let msg = BatchMessage {
+            batch_id: 1,
+            entries: vec![BatchEntry {
+                destination_chain_id: 1,
+                value: 1,
+            }],
+        };
+        let mut padded = msg.encode();
+        padded.push(0);
+        assert!(BatchMessage::decode(&padded).is_err());
+    }
+
+    #[test]
+    fn bridge_payload_round_trips_all_variants() {
+        let variants = vec![
+            BridgePayload::Value(value_message()),
+            BridgePayload::Text(TextMessage {
+                nick: "nick".to_string(),
+                text: "text".to_string(),
+            }),
+            BridgePayload::Batch(BatchMessage {
+                batch_id: 9,
+                entries: vec![BatchEntry {
+                    destination_chain_id: 3,
+                    value: 5,
+                }],
+            }),
+            BridgePayload::CctpTransfer(cctp_transfer_envelope()),
+        ];
+
+        for variant in variants {
+            let encoded = variant.encode();
+            assert_eq!(BridgePayload::decode(&encoded, false).unwrap(), variant);
+        }
+    }
+
+    fn cctp_transfer_envelope() -> CctpTransferEnvelope {
+        CctpTransferEnvelope {
+            cctp_nonce: 42,
+            source_domain: 5,
+            value: u128::MAX,
+            destination_chain_id: 2,
+        }
+    }
+
+    #[test]
+    fn cctp_transfer_envelope_round_trips_through_encode_decode() {
+        let msg = cctp_transfer_envelope();
+        assert_eq!(CctpTransferEnvelope::decode(&msg.encode()).unwrap(), msg);
+    }
+
+    #[test]
+    fn cctp_transfer_envelope_round_trips_through_write_read() {
+        let msg = cctp_transfer_envelope();
+        let mut buf = Vec::new();
+        msg.write(&mut buf).unwrap();
+        assert_eq!(buf.len(), CctpTransferEnvelope::PAYLOAD_SIZE);
+        let mut cursor = io::Cursor::new(buf.as_slice());
+        assert_eq!(CctpTransferEnvelope::read(&mut cursor).unwrap(), msg);
+    }
+
+    #[test]
+    fn cctp_transfer_envelope_rejects_short_and_padded_payloads() {
+        let encoded = cctp_transfer_envelope().encode();
+        assert!(CctpTransferEnvelope::decode(&encoded[..encoded.len() - 1]).is_err());
+
+        let mut padded = encoded.clone();
+        padded.push(0);
+        assert!(CctpTransferEnvelope::decode(&padded).is_err());
+    }
+
+    #[test]
+    fn payload_kind_round_trips_all_variants() {
+        let variants = vec![
+            PayloadKind::Value(u128::MAX),
+            PayloadKind::Raw(vec![1, 2, 3, 4]),
+            PayloadKind::Text {
+                nick: "nick".to_string(),
+                text: "hello from the general-purpose bus".to_string(),
+            },
+        ];
+
+        for variant in variants {
+            let encoded = variant.try_to_vec().unwrap();
+            assert_eq!(PayloadKind::try_from_slice(&encoded).unwrap(), variant);
+        }
+    }
+
+    #[test]
+    fn payload_kind_rejects_truncated_bytes() {
+        let encoded = PayloadKind::Text {
+            nick: "nick".to_string(),
+            text: "text".to_string(),
+        }
+        .try_to_vec()
+        .unwrap();
+        assert!(PayloadKind::try_from_slice(&encoded[..encoded.len() - 1]).is_err());
+    }
+}
@@ -1,11 +1,27 @@
 use anchor_lang::prelude::*;
 
+use crate::message::PayloadKind;
+
+/// Maximum number of Wormhole-chain-id -> CCTP-domain mappings stored in `Config`
+pub const MAX_CCTP_CHAINS: usize = 8;
+
+/// One entry of the Wormhole chain ID <-> Circle CCTP destination domain map
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CctpDomainMapping {
+    pub chain_id: u16,
+    pub domain: u32,
+}
+
 /// Program configuration account
 #[account]
 #[derive(Default)]
 pub struct Config {
     /// Program owner (can register emitters)
     pub owner: Pubkey,
+    /// Staged owner for a `transfer_ownership`/`accept_ownership` rotation; `Pubkey::default()` when none is pending
+    pub pending_owner: Pubkey,
+    /// When true, `send_value`/`receive_value` are rejected with `BridgePaused`
+    pub paused: bool,
     /// Wormhole program ID
     pub wormhole_program: Pubkey,
     /// Wormhole bridge (core) account
@@ -20,6 +36,18 @@ pub struct Config {
     pub chain_id: u16,
     /// Nonce for outbound messages
     pub nonce: u32,
+    /// Circle CCTP Token Messenger Minter program
+    pub cctp_token_messenger_minter: Pubkey,
+    /// Circle CCTP Message Transmitter program
+    pub cctp_message_transmitter: Pubkey,
+    /// USDC mint bridged via CCTP
+    pub usdc_mint: Pubkey,
+    /// Wormhole chain ID -> CCTP destination domain entries in use
+    pub cctp_domain_count: u8,
+    /// Wormhole chain ID -> CCTP destination domain map
+    pub cctp_domains: [CctpDomainMapping; MAX_CCTP_CHAINS],
+    /// Wormhole Token Bridge program, used by `send_tokens`/`redeem_tokens`
+    pub token_bridge_program: Pubkey,
 }
 
 impl Config {
@@ -27,7 +55,39 @@ impl Config {
 
     /// Config account size
     /// 8 (discriminator) + 32*6 (pubkeys) + 2 (chain_id) + 4 (nonce)
-    pub const SPACE: usize = 8 + 32 * 6 + 2 + 4;
+    ///   + 32*3 (cctp program/mint pubkeys) + 1 (cctp_domain_count)
+    ///   + MAX_CCTP_CHAINS * 6 (chain_id + domain per entry) + 32 (token_bridge_program)
+    ///   + 32 (pending_owner) + 1 (paused)
+    pub const SPACE: usize =
+        8 + 32 * 6 + 2 + 4 + 32 * 3 + 1 + MAX_CCTP_CHAINS * 6 + 32 + 32 + 1;
+
+    /// Look up the CCTP destination domain registered for a Wormhole chain ID
+    pub fn cctp_domain_for(&self, chain_id: u16) -> Option<u32> {
+        self.cctp_domains[..self.cctp_domain_count as usize]
+            .iter()
+            .find(|entry| entry.chain_id == chain_id)
+            .map(|entry| entry.domain)
+    }
+
+    /// Insert or update the CCTP destination domain for a Wormhole chain ID
+    pub fn set_cctp_domain(&mut self, chain_id: u16, domain: u32) -> Result<()> {
+        let count = self.cctp_domain_count as usize;
+        if let Some(entry) = self.cctp_domains[..count]
+            .iter_mut()
+            .find(|entry| entry.chain_id == chain_id)
+        {
+            entry.domain = domain;
+            return Ok(());
+        }
+
+        require!(
+            count < MAX_CCTP_CHAINS,
+            crate::error::MessageBridgeError::CctpDomainTableFull
+        );
+        self.cctp_domains[count] = CctpDomainMapping { chain_id, domain };
+        self.cctp_domain_count += 1;
+        Ok(())
+    }
 }
 
 /// Registered foreign emitter (one per chain)
@@ -38,21 +98,28 @@ pub struct ForeignEmitter {
     pub chain_id: u16,
     /// Emitter address on the foreign chain (32 bytes)
     pub address: [u8; 32],
-    /// Payload format: true = default 18-byte (Solana/EVM), false = Aztec 50-byte (with txId)
+    /// Payload format: true = legacy fixed-width layout, false = versioned `BridgePayload` codec
     pub is_default_payload: bool,
+    /// Optional allow-listed `sender` origin for this emitter; `[0u8; 32]` means unrestricted
+    pub allowed_sender: [u8; 32],
 }
 
 impl ForeignEmitter {
     pub const SEED_PREFIX: &'static [u8] = b"foreign_emitter";
 
     /// ForeignEmitter account size
-    /// 8 (discriminator) + 2 (chain_id) + 32 (address) + 1 (is_default_payload)
-    pub const SPACE: usize = 8 + 2 + 32 + 1;
+    /// 8 (discriminator) + 2 (chain_id) + 32 (address) + 1 (is_default_payload) + 32 (allowed_sender)
+    pub const SPACE: usize = 8 + 2 + 32 + 1 + 32;
 
     /// Verify that an emitter address matches this registered emitter
     pub fn verify(&self, emitter_address: &[u8; 32]) -> bool {
         self.address == *emitter_address
     }
+
+    /// Verify that a message sender is allowed, if an allow-list is configured
+    pub fn verify_sender(&self, sender: &[u8; 32]) -> bool {
+        self.allowed_sender == [0u8; 32] || self.allowed_sender == *sender
+    }
 }
 
 /// Received message (for replay protection)
@@ -67,14 +134,16 @@ pub struct ReceivedMessage {
     pub value: u128,
     /// Batch ID (for grouping)
     pub batch_id: u32,
+    /// Origin account that requested the transfer on the source chain
+    pub sender: [u8; 32],
 }
 
 impl ReceivedMessage {
     pub const SEED_PREFIX: &'static [u8] = b"received";
 
     /// ReceivedMessage account size
-    /// 8 (discriminator) + 8 (sequence) + 2 (emitter_chain) + 16 (value) + 4 (batch_id)
-    pub const SPACE: usize = 8 + 8 + 2 + 16 + 4;
+    /// 8 (discriminator) + 8 (sequence) + 2 (emitter_chain) + 16 (value) + 4 (batch_id) + 32 (sender)
+    pub const SPACE: usize = 8 + 8 + 2 + 16 + 4 + 32;
 }
 
 /// Wormhole emitter account (PDA that signs messages)
@@ -105,3 +174,116 @@ impl CurrentValue {
     pub const SPACE: usize = 8 + 16;
 }
 
+/// Maximum length of a `TextMessage` nick, in bytes
+pub const MAX_NICK_LEN: usize = 32;
+/// Maximum length of a `TextMessage` body, in bytes
+pub const MAX_TEXT_LEN: usize = 280;
+/// Number of recent text messages retained per emitter chain
+pub const INBOX_CAPACITY: usize = 10;
+
+/// One text message stored in a `MessageInbox` ring buffer
+///
+/// `nick`/`text` are fixed-size byte buffers (bounded by `MAX_NICK_LEN`/
+/// `MAX_TEXT_LEN`) so `MessageInbox` stays a fixed-space Anchor account;
+/// `nick_len`/`text_len` record the actual UTF-8 length in use.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct InboxEntry {
+    pub sequence: u64,
+    pub timestamp: i64,
+    pub nick_len: u8,
+    pub nick: [u8; MAX_NICK_LEN],
+    pub text_len: u16,
+    pub text: [u8; MAX_TEXT_LEN],
+}
+
+impl Default for InboxEntry {
+    fn default() -> Self {
+        Self {
+            sequence: 0,
+            timestamp: 0,
+            nick_len: 0,
+            nick: [0u8; MAX_NICK_LEN],
+            text_len: 0,
+            text: [0u8; MAX_TEXT_LEN],
+        }
+    }
+}
+
+impl InboxEntry {
+    pub const SPACE: usize = 8 + 8 + 1 + MAX_NICK_LEN + 2 + MAX_TEXT_LEN;
+
+    fn new(nick: &str, text: &str, sequence: u64, timestamp: i64) -> Self {
+        let mut entry = Self {
+            sequence,
+            timestamp,
+            nick_len: nick.len() as u8,
+            text_len: text.len() as u16,
+            ..Default::default()
+        };
+        entry.nick[..nick.len()].copy_from_slice(nick.as_bytes());
+        entry.text[..text.len()].copy_from_slice(text.as_bytes());
+        entry
+    }
+
+    pub fn nick(&self) -> &str {
+        std::str::from_utf8(&self.nick[..self.nick_len as usize]).unwrap_or_default()
+    }
+
+    pub fn text(&self) -> &str {
+        std::str::from_utf8(&self.text[..self.text_len as usize]).unwrap_or_default()
+    }
+}
+
+/// Maximum Borsh-encoded size of a `PayloadKind` accepted by `receive_message`,
+/// bounding how large a `MessagePayload` account `receive_message` will `realloc` to
+pub const MAX_MESSAGE_PAYLOAD_LEN: usize = 1024;
+
+/// Storage for one arbitrary-payload message received via `receive_message`
+///
+/// Unlike `MessageInbox`'s fixed-capacity ring buffer, `payload` is an
+/// open-ended `PayloadKind`, so this account is created at the minimum
+/// `BASE_SPACE` and `realloc`'d in the handler to fit the decoded payload.
+#[account]
+pub struct MessagePayload {
+    pub emitter_chain: u16,
+    pub sequence: u64,
+    pub payload: PayloadKind,
+}
+
+impl MessagePayload {
+    pub const SEED_PREFIX: &'static [u8] = b"message_payload";
+
+    /// Space before the variable-length `payload` field:
+    /// 8 (discriminator) + 2 (emitter_chain) + 8 (sequence)
+    pub const BASE_SPACE: usize = 8 + 2 + 8;
+}
+
+/// Ring buffer of the most recently received text messages for one emitter chain
+#[account]
+#[derive(Default)]
+pub struct MessageInbox {
+    /// Wormhole chain ID this inbox holds messages from
+    pub emitter_chain: u16,
+    /// Index the next message will be written to
+    pub head: u8,
+    /// Number of valid entries (caps at `INBOX_CAPACITY`)
+    pub len: u8,
+    /// Ring buffer storage, oldest entry evicted first once full
+    pub entries: [InboxEntry; INBOX_CAPACITY],
+}
+
+impl MessageInbox {
+    pub const SEED_PREFIX: &'static [u8] = b"message_inbox";
+
+    /// MessageInbox account size
+    /// 8 (discriminator) + 2 (emitter_chain) + 1 (head) + 1 (len) + INBOX_CAPACITY * InboxEntry::SPACE
+    pub const SPACE: usize = 8 + 2 + 1 + 1 + INBOX_CAPACITY * InboxEntry::SPACE;
+
+    /// Append a message, evicting the oldest entry once the buffer is full
+    pub fn push(&mut self, nick: &str, text: &str, sequence: u64, timestamp: i64) {
+        self.entries[self.head as usize] = InboxEntry::new(nick, text, sequence, timestamp);
+        self.head = (self.head + 1) % INBOX_CAPACITY as u8;
+        self.len = (self.len + 1).min(INBOX_CAPACITY as u8);
+    }
+}
+
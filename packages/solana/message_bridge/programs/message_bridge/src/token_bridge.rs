@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+
+use crate::error::MessageBridgeError;
+
+/// Wire instruction indices for the Wormhole Token Bridge program.
+///
+/// Unlike the Circle CCTP programs in `cctp.rs` (Anchor programs dispatched
+/// by an 8-byte sighash), the Token Bridge is a native program whose
+/// instructions are a Borsh-encoded enum, so the discriminator here is a
+/// single leading byte - the enum variant's index.
+mod instruction {
+    pub const COMPLETE_NATIVE: u8 = 2;
+    pub const COMPLETE_WRAPPED: u8 = 3;
+    pub const TRANSFER_WRAPPED: u8 = 4;
+    pub const TRANSFER_NATIVE: u8 = 5;
+}
+
+#[derive(AnchorSerialize)]
+struct TransferArgs {
+    nonce: u32,
+    amount: u64,
+    fee: u64,
+    recipient: [u8; 32],
+    recipient_chain: u16,
+}
+
+/// CPI into the Token Bridge `transfer_native`/`transfer_wrapped` instruction,
+/// locking (native) or burning (wrapped) `amount` of the token held in `from`
+/// and having the Token Bridge post its own attestation VAA addressed to
+/// `recipient`/`recipient_chain` through the Wormhole Core Bridge.
+///
+/// `custody_or_wrapped_meta` and `custody_signer_or_mint_authority` carry one
+/// of two Token Bridge PDAs depending on `is_wrapped`: the token's custody
+/// account and its signer for a native transfer, or the wrapped asset's
+/// metadata account and mint authority PDA for a wrapped transfer.
+#[allow(clippy::too_many_arguments)]
+pub fn transfer<'info>(
+    token_bridge_program: &AccountInfo<'info>,
+    wormhole_program: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    token_bridge_config: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    custody_or_wrapped_meta: &AccountInfo<'info>,
+    authority_signer: &AccountInfo<'info>,
+    custody_signer_or_mint_authority: &AccountInfo<'info>,
+    wormhole_bridge: &AccountInfo<'info>,
+    wormhole_message: &AccountInfo<'info>,
+    wormhole_emitter: &AccountInfo<'info>,
+    wormhole_sequence: &AccountInfo<'info>,
+    wormhole_fee_collector: &AccountInfo<'info>,
+    clock: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    is_wrapped: bool,
+    nonce: u32,
+    amount: u64,
+    fee: u64,
+    recipient: [u8; 32],
+    recipient_chain: u16,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let args = TransferArgs {
+        nonce,
+        amount,
+        fee,
+        recipient,
+        recipient_chain,
+    };
+
+    let mut data = vec![if is_wrapped {
+        instruction::TRANSFER_WRAPPED
+    } else {
+        instruction::TRANSFER_NATIVE
+    }];
+    data.extend_from_slice(
+        &args
+            .try_to_vec()
+            .map_err(|_| error!(MessageBridgeError::InvalidPayload))?,
+    );
+
+    let accounts = vec![
+        AccountMeta::new(*payer.key, true),
+        AccountMeta::new(*token_bridge_config.key, false),
+        AccountMeta::new(*from.key, false),
+        AccountMeta::new(*mint.key, false),
+        AccountMeta::new(*custody_or_wrapped_meta.key, false),
+        AccountMeta::new_readonly(*authority_signer.key, false),
+        AccountMeta::new(*custody_signer_or_mint_authority.key, false),
+        AccountMeta::new(*wormhole_bridge.key, false),
+        AccountMeta::new(*wormhole_message.key, true),
+        AccountMeta::new_readonly(*wormhole_emitter.key, false),
+        AccountMeta::new(*wormhole_sequence.key, false),
+        AccountMeta::new(*wormhole_fee_collector.key, false),
+        AccountMeta::new_readonly(*clock.key, false),
+        AccountMeta::new_readonly(*rent.key, false),
+        AccountMeta::new_readonly(*system_program.key, false),
+        AccountMeta::new_readonly(*wormhole_program.key, false),
+        AccountMeta::new_readonly(*token_program.key, false),
+    ];
+
+    let ix = Instruction {
+        program_id: *token_bridge_program.key,
+        accounts,
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            payer.clone(),
+            token_bridge_config.clone(),
+            from.clone(),
+            mint.clone(),
+            custody_or_wrapped_meta.clone(),
+            authority_signer.clone(),
+            custody_signer_or_mint_authority.clone(),
+            wormhole_bridge.clone(),
+            wormhole_message.clone(),
+            wormhole_emitter.clone(),
+            wormhole_sequence.clone(),
+            wormhole_fee_collector.clone(),
+            clock.clone(),
+            rent.clone(),
+            system_program.clone(),
+            wormhole_program.clone(),
+            token_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+
+    Ok(())
+}
+
+/// CPI into the Token Bridge `complete_native`/`complete_wrapped` instruction,
+/// verifying the Token Bridge's own posted VAA and releasing (native) or
+/// minting (wrapped) the transferred amount into `to`.
+#[allow(clippy::too_many_arguments)]
+pub fn complete_transfer<'info>(
+    token_bridge_program: &AccountInfo<'info>,
+    wormhole_program: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    token_bridge_config: &AccountInfo<'info>,
+    token_bridge_posted_vaa: &AccountInfo<'info>,
+    claim: &AccountInfo<'info>,
+    foreign_endpoint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    fee_recipient: &AccountInfo<'info>,
+    mint_or_custody: &AccountInfo<'info>,
+    custody_signer_or_mint_authority: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    is_wrapped: bool,
+) -> Result<()> {
+    let data = vec![if is_wrapped {
+        instruction::COMPLETE_WRAPPED
+    } else {
+        instruction::COMPLETE_NATIVE
+    }];
+
+    let accounts = vec![
+        AccountMeta::new(*payer.key, true),
+        AccountMeta::new_readonly(*token_bridge_config.key, false),
+        AccountMeta::new_readonly(*token_bridge_posted_vaa.key, false),
+        AccountMeta::new(*claim.key, false),
+        AccountMeta::new_readonly(*foreign_endpoint.key, false),
+        AccountMeta::new(*to.key, false),
+        AccountMeta::new(*fee_recipient.key, false),
+        AccountMeta::new(*mint_or_custody.key, false),
+        AccountMeta::new(*custody_signer_or_mint_authority.key, false),
+        AccountMeta::new_readonly(*rent.key, false),
+        AccountMeta::new_readonly(*system_program.key, false),
+        AccountMeta::new_readonly(*wormhole_program.key, false),
+        AccountMeta::new_readonly(*token_program.key, false),
+    ];
+
+    let ix = Instruction {
+        program_id: *token_bridge_program.key,
+        accounts,
+        data,
+    };
+
+    invoke(
+        &ix,
+        &[
+            payer.clone(),
+            token_bridge_config.clone(),
+            token_bridge_posted_vaa.clone(),
+            claim.clone(),
+            foreign_endpoint.clone(),
+            to.clone(),
+            fee_recipient.clone(),
+            mint_or_custody.clone(),
+            custody_signer_or_mint_authority.clone(),
+            rent.clone(),
+            system_program.clone(),
+            wormhole_program.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    Ok(())
+}
@@ -28,4 +28,34 @@ pub enum MessageBridgeError {
 
     #[msg("Insufficient fee for Wormhole message")]
     InsufficientFee,
+
+    #[msg("This instruction does not support this payload kind")]
+    UnsupportedPayloadKind,
+
+    #[msg("Message sender is not on the emitter's allow-list")]
+    InvalidSender,
+
+    #[msg("No CCTP destination domain registered for this chain")]
+    CctpDomainNotConfigured,
+
+    #[msg("CCTP chain-id/domain mapping table is full")]
+    CctpDomainTableFull,
+
+    #[msg("Token account mint does not match the configured USDC mint")]
+    CctpMintMismatch,
+
+    #[msg("CCTP mint receipt does not match the expected deposit-for-burn")]
+    CctpMintReceiptMismatch,
+
+    #[msg("Token account mint does not match the Token Bridge transfer's mint")]
+    TokenBridgeMintMismatch,
+
+    #[msg("Token Bridge transfer or mint authority mismatch")]
+    TokenBridgeAuthorityMismatch,
+
+    #[msg("CCTP nonce or attestation does not match the paired Wormhole envelope")]
+    CctpNonceMismatch,
+
+    #[msg("The bridge is paused")]
+    BridgePaused,
 }